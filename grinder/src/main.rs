@@ -0,0 +1,142 @@
+//grinds u64 seeds whose pool mint PDA (seeds = ["pool-mint", seed.to_le_bytes()]) matches the vanity rule enforced by create.
+//usage: mint-grinder [--pattern meme] [--prefix] [--case-sensitive] [--count 100] [--threads N] [--start 0] [--out seeds.txt]
+//every line of the output file is "<seed> <mint>", the seeds can be loaded with add_mint_seeds
+use std::{
+    env,
+    fs::OpenOptions,
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Instant,
+};
+
+use anchor_lang::prelude::Pubkey;
+use curve_launchpad::{
+    instructions::{encode_base58_address, matches_vanity},
+    state::MintSeedPool,
+};
+
+struct Args {
+    pattern: String,
+    is_prefix: bool,
+    case_sensitive: bool,
+    count: u64,
+    threads: u64,
+    start: u64,
+    out: String,
+}
+
+fn parse_args() -> Args {
+    let mut args = Args {
+        pattern: "meme".to_string(),
+        is_prefix: false,
+        case_sensitive: false,
+        count: 100,
+        threads: thread::available_parallelism().map_or(1, |n| n.get() as u64),
+        start: 0,
+        out: "seeds.txt".to_string(),
+    };
+
+    let mut iter = env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--prefix" => {
+                args.is_prefix = true;
+                continue;
+            }
+            "--case-sensitive" => {
+                args.case_sensitive = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        let value = iter.next().unwrap_or_else(|| panic!("missing value for {}", flag));
+        match flag.as_str() {
+            "--pattern" => args.pattern = value,
+            "--count" => args.count = value.parse().expect("invalid --count"),
+            "--threads" => args.threads = value.parse().expect("invalid --threads"),
+            "--start" => args.start = value.parse().expect("invalid --start"),
+            "--out" => args.out = value,
+            _ => panic!("unknown flag {}", flag),
+        }
+    }
+    assert!(args.threads > 0, "--threads must be positive");
+
+    args
+}
+
+//same comparison as Global::mint_matches_vanity
+fn matches(mint: &Pubkey, args: &Args) -> bool {
+    let mut address = [0u8; 44];
+    let len = encode_base58_address(&mint.to_bytes(), &mut address);
+    matches_vanity(&address[..len], args.pattern.as_bytes(), args.is_prefix, args.case_sensitive)
+}
+
+fn main() {
+    let args = Arc::new(parse_args());
+
+    let done = Arc::new(AtomicBool::new(false));
+    let tried = Arc::new(AtomicU64::new(0));
+    let (sender, receiver) = mpsc::channel::<(u64, Pubkey)>();
+
+    //thread i checks start + i, start + i + threads, ...
+    let workers: Vec<_> = (0..args.threads)
+        .map(|i| {
+            let done = done.clone();
+            let tried = tried.clone();
+            let sender = sender.clone();
+            let args = args.clone();
+            let (start, step) = (args.start + i, args.threads);
+            thread::spawn(move || {
+                let mut seed = start;
+                while !done.load(Ordering::Relaxed) {
+                    let mint = MintSeedPool::mint_address(seed);
+                    if matches(&mint, &args) && sender.send((seed, mint)).is_err() {
+                        break;
+                    }
+                    tried.fetch_add(1, Ordering::Relaxed);
+                    seed = match seed.checked_add(step) {
+                        Some(next) => next,
+                        None => break,
+                    };
+                }
+            })
+        })
+        .collect();
+    drop(sender);
+
+    let mut out = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&args.out)
+        .expect("failed to open output file");
+
+    let started = Instant::now();
+    let mut found = 0;
+    for (seed, mint) in receiver.iter() {
+        writeln!(out, "{} {}", seed, mint).expect("failed to write output file");
+        found += 1;
+        println!(
+            "[{}/{}] seed {} -> {} ({} tried, {:.1}s)",
+            found,
+            args.count,
+            seed,
+            mint,
+            tried.load(Ordering::Relaxed),
+            started.elapsed().as_secs_f64()
+        );
+        if found >= args.count {
+            break;
+        }
+    }
+
+    done.store(true, Ordering::Relaxed);
+    drop(receiver);
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
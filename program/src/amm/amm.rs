@@ -1,5 +1,8 @@
 use std::fmt;
 
+/// Marginal prices are quoted in lamports per token base unit, scaled by this factor.
+pub const PRICE_PRECISION: u128 = 1_000_000_000_000;
+
 #[derive(Debug)]
 pub struct BuyResult {
     pub token_amount: u64,
@@ -51,6 +54,23 @@ impl AMM {
         Some(amount_needed)
     }
 
+    // Largest token amount whose buy price does not exceed the given SOL amount
+    pub fn get_tokens_for_sol(&self, sol: u128) -> Option<u128> {
+        if sol <= 1 {
+            return None;
+        }
+
+        let product_of_reserves = self.virtual_sol_reserves.checked_mul(self.virtual_token_reserves)?;
+        let new_virtual_sol_reserves = self.virtual_sol_reserves.checked_add(sol)?.checked_sub(1)?;
+        let new_virtual_token_reserves = product_of_reserves
+            .checked_add(new_virtual_sol_reserves)?
+            .checked_sub(1)?
+            .checked_div(new_virtual_sol_reserves)?;
+        let tokens = self.virtual_token_reserves.checked_sub(new_virtual_token_reserves)?;
+
+        Some(tokens.min(self.real_token_reserves))
+    }
+
     pub fn apply_buy(&mut self, token_amount: u128) -> Option<BuyResult> {
         let final_token_amount = if token_amount > self.real_token_reserves {
             self.real_token_reserves
@@ -88,7 +108,7 @@ impl AMM {
     }
 
     pub fn get_sell_price(&self, tokens: u128) -> Option<u128> {
-        if tokens <= 0 || tokens > self.virtual_token_reserves {
+        if tokens == 0 || tokens > self.virtual_token_reserves {
             return None;
         }
 
@@ -100,6 +120,16 @@ impl AMM {
 
         Some(sol_received.min(self.real_sol_reserves))
     }
+
+    pub fn get_marginal_price(&self) -> Option<u128> {
+        if self.virtual_token_reserves == 0 {
+            return None;
+        }
+
+        self.virtual_sol_reserves
+            .checked_mul(PRICE_PRECISION)?
+            .checked_div(self.virtual_token_reserves)
+    }
 }
 
 
@@ -115,7 +145,7 @@ impl fmt::Display for AMM {
 
 #[cfg(test)]
 mod tests {
-    use crate::amm::AMM;
+    use crate::amm::{AMM, PRICE_PRECISION};
 
     #[test]
     fn test_buy_and_sell_too_much() {
@@ -208,6 +238,38 @@ mod tests {
         assert_eq!(amm.real_sol_reserves, 621);
     }
 
+    #[test]
+    fn test_get_tokens_for_sol() {
+        let amm = AMM::new(1000, 1000, 500, 500, 1000);
+
+        assert_eq!(amm.get_tokens_for_sol(0), None);
+
+        // The buy price of the returned amount never exceeds the SOL provided
+        let tokens = amm.get_tokens_for_sol(112).unwrap();
+        assert_eq!(tokens, 99);
+        assert!(amm.get_buy_price(tokens).unwrap() <= 112);
+
+        // Capped by the real token reserves
+        assert_eq!(amm.get_tokens_for_sol(1_000_000), Some(500));
+    }
+
+    #[test]
+    fn test_get_marginal_price() {
+        let mut amm = AMM::new(1000, 1000, 500, 500, 1000);
+        assert_eq!(amm.get_marginal_price(), Some(PRICE_PRECISION));
+
+        // Buying moves the price up, selling moves it back down
+        amm.apply_buy(100).unwrap();
+        let after_buy = amm.get_marginal_price().unwrap();
+        assert!(after_buy > PRICE_PRECISION);
+
+        amm.apply_sell(100).unwrap();
+        assert!(amm.get_marginal_price().unwrap() < after_buy);
+
+        let empty = AMM::new(1000, 0, 0, 0, 1000);
+        assert_eq!(empty.get_marginal_price(), None);
+    }
+
     #[test]
     fn test_get_buy_price() {
         let amm = AMM::new(1000, 1000, 500, 500, 1000);
@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 pub mod amm;
 pub use amm::*;
\ No newline at end of file
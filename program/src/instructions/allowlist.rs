@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    state::{AllowlistEntry, BondingCurve},
+    AllowlistEvent,
+};
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AddToAllowlist<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [AllowlistEntry::SEED_PREFIX, mint.key().as_ref(), user.as_ref()],
+        bump,
+    )]
+    allowlist_entry: Box<Account<'info, AllowlistEntry>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, user: Pubkey) -> Result<()> {
+    ctx.accounts.allowlist_entry.mint = ctx.accounts.mint.key();
+    ctx.accounts.allowlist_entry.user = user;
+
+    emit_allowlist_event(&ctx.accounts.mint.key(), &user, true)
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct RemoveFromAllowlist<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [AllowlistEntry::SEED_PREFIX, mint.key().as_ref(), user.as_ref()],
+        bump,
+    )]
+    allowlist_entry: Box<Account<'info, AllowlistEntry>>,
+}
+
+pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>, user: Pubkey) -> Result<()> {
+    emit_allowlist_event(&ctx.accounts.mint.key(), &user, false)
+}
+
+fn emit_allowlist_event(mint: &Pubkey, user: &Pubkey, allowed: bool) -> Result<()> {
+    let allowlist_event = AllowlistEvent {
+        mint: mint.to_string(),
+        user: user.to_string(),
+        allowed,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&allowlist_event).unwrap();
+
+    msg!("allowlist:{}", serialized);
+
+    Ok(())
+}
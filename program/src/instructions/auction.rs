@@ -0,0 +1,335 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    amm, calculate_fee,
+    state::{AllowlistEntry, AuctionCommitment, BondingCurve, FeeAccount, Global, UserInviteStats},
+    ClaimAuctionEvent, CommitAuctionEvent, CompleteEvent, CurveLaunchpadError, SettleAuctionEvent,
+};
+
+#[derive(Accounts)]
+pub struct CommitAuction<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + AuctionCommitment::INIT_SPACE,
+        seeds = [AuctionCommitment::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    auction_commitment: Box<Account<'info, AuctionCommitment>>,
+
+    //only needed when the launch has an allowlist phase
+    #[account(
+        seeds = [AllowlistEntry::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    allowlist_entry: Option<Box<Account<'info, AllowlistEntry>>>,
+
+    system_program: Program<'info, System>,
+}
+
+//committed sol is escrowed in the bonding curve account, outside of real_sol_reserves, until settlement
+pub fn commit_auction(ctx: Context<CommitAuction>, sol_amount: u64) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+
+    ctx.accounts
+        .bonding_curve
+        .check_auction_commit(curr_time, ctx.accounts.allowlist_entry.is_some())?;
+    require!(sol_amount > 0, CurveLaunchpadError::InsufficientSOL);
+
+    let transfer_instruction = system_instruction::transfer(
+        ctx.accounts.user.key,
+        ctx.accounts.bonding_curve.to_account_info().key,
+        sol_amount,
+    );
+    anchor_lang::solana_program::program::invoke_signed(
+        &transfer_instruction,
+        &[
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.bonding_curve.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[],
+    )?;
+
+    let auction_commitment = &mut ctx.accounts.auction_commitment;
+    auction_commitment.mint = ctx.accounts.mint.key();
+    auction_commitment.user = ctx.accounts.user.key();
+    auction_commitment.sol_amount += sol_amount;
+    ctx.accounts.bonding_curve.auction.total_committed += sol_amount;
+
+    let commit_event = CommitAuctionEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.user.key().to_string(),
+        sol_amount,
+        user_committed: auction_commitment.sol_amount,
+        total_committed: ctx.accounts.bonding_curve.auction.total_committed,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&commit_event).unwrap();
+
+    msg!("commitAuction:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+
+    system_program: Program<'info, System>,
+}
+
+//permissionless crank, all commitments buy as one order so every participant pays the same price
+pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(
+        bonding_curve.auction.commit_end_time > 0 && curr_time >= bonding_curve.auction.commit_end_time,
+        CurveLaunchpadError::AuctionNotEnded
+    );
+    require!(!bonding_curve.auction.settled, CurveLaunchpadError::AuctionAlreadySettled);
+    //a curve that completed or expired meanwhile buys nothing and refunds every commitment
+    let is_tradable = !bonding_curve.is_complete() && !bonding_curve.is_expired(curr_time);
+
+    //protocol and creator fees are charged on the sol the curve absorbs, invite fees don't apply to the auction
+    let total_committed = bonding_curve.auction.total_committed;
+    let creator_fee_basis_points = bonding_curve.creator_fee_basis_points(true, curr_time);
+    let fee_basis_points = ctx.accounts.global.fee_basis_points + creator_fee_basis_points;
+
+    let mut amm = amm::amm::AMM::new(
+        bonding_curve.virtual_sol_reserves as u128,
+        bonding_curve.virtual_token_reserves as u128,
+        bonding_curve.real_sol_reserves as u128,
+        bonding_curve.real_token_reserves as u128,
+        ctx.accounts.global.initial_virtual_token_reserves as u128,
+    );
+
+    let sol_for_curve = total_committed as u128 * 10000 / (10000 + fee_basis_points) as u128;
+    let (token_amount, clearing_sol) = match amm.get_tokens_for_sol(sol_for_curve) {
+        Some(tokens) if tokens > 0 && is_tradable => {
+            let buy_result = amm.apply_buy(tokens).ok_or(CurveLaunchpadError::InsufficientTokens)?;
+            (buy_result.token_amount, buy_result.sol_amount)
+        }
+        _ => (0, 0),
+    };
+
+    let protocol_fee = calculate_fee(clearing_sol, ctx.accounts.global.fee_basis_points);
+    let creator_fee = calculate_fee(clearing_sol, creator_fee_basis_points);
+    let fee = protocol_fee + creator_fee;
+
+    if fee > 0 {
+        **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= fee;
+        **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? += fee;
+        ctx.accounts.fee_account.received += fee;
+        let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
+        require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+    }
+
+    ctx.accounts.fee_recipient_invite_account.profit_claimable += protocol_fee;
+    if ctx.accounts.bonding_curve.creator_fee_renounced {
+        ctx.accounts.fee_recipient_invite_account.profit_claimable += creator_fee;
+    } else {
+        ctx.accounts.bonding_curve.creator_fee_claimable += creator_fee;
+    }
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_token_reserves = amm.real_token_reserves as u64;
+    bonding_curve.real_sol_reserves = amm.real_sol_reserves as u64;
+    bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
+    bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
+    bonding_curve.update_time = curr_time as u64;
+
+    bonding_curve.auction.settled = true;
+    bonding_curve.auction.total_tokens = token_amount;
+    //sol the curve could not absorb is refunded pro-rata on claim
+    bonding_curve.auction.refund_sol = total_committed
+        .checked_sub(clearing_sol + fee)
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+
+    let settle_event = SettleAuctionEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        total_committed,
+        token_amount,
+        sol_amount: clearing_sol,
+        fee,
+        refund_sol: bonding_curve.auction.refund_sol,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&settle_event).unwrap();
+
+    msg!("settleAuction:{}", serialized);
+
+    if bonding_curve.real_token_reserves == 0 {
+        bonding_curve.complete = true;
+
+        let complete_event = CompleteEvent {
+            user: ctx.accounts.user.key().to_string(),
+            mint: ctx.accounts.mint.key().to_string(),
+            bonding_curve: ctx.accounts.bonding_curve.key().to_string(),
+            timestamp: curr_time,
+        };
+        let serialized = serde_json::to_string(&complete_event).unwrap();
+        msg!("completelog:{}", serialized);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimAuction<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [AuctionCommitment::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    auction_commitment: Box<Account<'info, AuctionCommitment>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn claim_auction(ctx: Context<ClaimAuction>) -> Result<()> {
+    let auction = ctx.accounts.bonding_curve.auction;
+    require!(auction.settled, CurveLaunchpadError::AuctionNotSettled);
+    require!(
+        !ctx.accounts.auction_commitment.claimed,
+        CurveLaunchpadError::AuctionAlreadyClaimed
+    );
+
+    let committed = ctx.accounts.auction_commitment.sol_amount as u128;
+    let token_amount = (auction.total_tokens as u128 * committed / auction.total_committed as u128) as u64;
+    let refund_sol = (auction.refund_sol as u128 * committed / auction.total_committed as u128) as u64;
+
+    ctx.accounts.auction_commitment.claimed = true;
+    ctx.accounts.bonding_curve.auction.claimed_tokens += token_amount;
+    ctx.accounts.bonding_curve.auction.claimed_refund_sol += refund_sol;
+
+    if token_amount > 0 {
+        let signer: [&[&[u8]]; 1] = [&[
+            BondingCurve::SEED_PREFIX,
+            ctx.accounts.mint.to_account_info().key.as_ref(),
+            &[ctx.bumps.bonding_curve],
+        ]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.bonding_curve.to_account_info(),
+                },
+                &signer,
+            ),
+            token_amount,
+        )?;
+    }
+
+    if refund_sol > 0 {
+        **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= refund_sol;
+        **ctx.accounts.user.try_borrow_mut_lamports()? += refund_sol;
+    }
+
+    let claim_event = ClaimAuctionEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.user.key().to_string(),
+        sol_committed: committed as u64,
+        token_amount,
+        refund_sol,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&claim_event).unwrap();
+
+    msg!("claimAuction:{}", serialized);
+
+    Ok(())
+}
@@ -0,0 +1,395 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    amm, book_trade_fee,
+    state::{AllowlistEntry, BondingCurve, FeeAccount, Global, UserInviteStats},
+    BatchTradeEvent, BatchTradeLegEvent, CompleteEvent, CurveLaunchpadError, TradeFees, MAX_BATCH_LEGS,
+};
+
+//number of remaining accounts supplied for every leg:
+//mint, bonding_curve, bonding_curve_token_account, user_token_account, allowlist_entry.
+//the user token account is the user's associated token account and must already exist,
+//the program id stands in for the allowlist entry when the user is not allowlisted
+pub const ACCOUNTS_PER_LEG: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TradeLeg {
+    pub mint: Pubkey,
+    pub is_buy: bool,
+    pub token_amount: u64,
+    //max_sol_cost for a buy, min_sol_output for a sell
+    pub limit: u64,
+}
+
+#[derive(Accounts)]
+pub struct BatchTrade<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
+        bump
+    )]
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    //referral chain, only needed when the trader has a referrer.
+    //validated against the chain in distribute_invite_fee
+    #[account(mut)]
+    parent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    great_grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account : Box<Account<'info,FeeAccount>>,
+
+    system_program: Program<'info, System>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn batch_trade<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchTrade<'info>>,
+    legs: Vec<TradeLeg>,
+    hash: &str,
+) -> Result<()> {
+    require!(
+        !legs.is_empty() && legs.len() <= MAX_BATCH_LEGS,
+        CurveLaunchpadError::InvalidBatchLegs,
+    );
+    require!(
+        ctx.remaining_accounts.len() == legs.len() * ACCOUNTS_PER_LEG,
+        CurveLaunchpadError::InvalidBatchLegs,
+    );
+    require!(has_unique_mints(&legs), CurveLaunchpadError::InvalidBatchLegs);
+
+    let curr_time = Clock::get()?.unix_timestamp;
+    let program_id = ctx.program_id;
+
+    //the volume tier is evaluated once, before any leg is traded, so the volume
+    //recorded by earlier legs does not change the tier of later ones
+    let (fee_tier, protocol_fee_basis_points) = ctx
+        .accounts
+        .global
+        .discounted_fee_basis_points(ctx.accounts.user_invite_account.rolling_volume(curr_time));
+    let user = ctx.accounts.user.to_account_info();
+
+    let mut total_fee: u64 = 0;
+    //buy fees are paid by the user in a single transfer after the last leg
+    let mut total_buy_fee: u64 = 0;
+    let mut total_volume: u64 = 0;
+    let mut leg_events: Vec<BatchTradeLegEvent> = Vec::with_capacity(legs.len());
+
+    for (i, leg) in legs.iter().enumerate() {
+        let accounts = &ctx.remaining_accounts[i * ACCOUNTS_PER_LEG..(i + 1) * ACCOUNTS_PER_LEG];
+
+        let mint: Account<'info, Mint> = Account::try_from(&accounts[0])?;
+        require_keys_eq!(mint.key(), leg.mint, CurveLaunchpadError::InvalidBatchLegs);
+
+        let (bonding_curve_key, bonding_curve_bump) = Pubkey::find_program_address(
+            &[BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+            program_id,
+        );
+        require_keys_eq!(accounts[1].key(), bonding_curve_key, CurveLaunchpadError::InvalidBatchLegs);
+        let mut bonding_curve: Account<'info, BondingCurve> = Account::try_from(&accounts[1])?;
+
+        let (bonding_curve_token_key, _) = Pubkey::find_program_address(
+            &[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve_key.as_ref()],
+            program_id,
+        );
+        require_keys_eq!(accounts[2].key(), bonding_curve_token_key, CurveLaunchpadError::InvalidBatchLegs);
+        let bonding_curve_token_account: Account<'info, TokenAccount> = Account::try_from(&accounts[2])?;
+
+        require_keys_eq!(
+            accounts[3].key(),
+            get_associated_token_address(&user.key(), &mint.key()),
+            CurveLaunchpadError::InvalidBatchLegs
+        );
+        let user_token_account: Account<'info, TokenAccount> = Account::try_from(&accounts[3])?;
+
+        let is_allowlisted = is_allowlisted(&accounts[4], &leg.mint, &user.key(), program_id)?;
+
+        require!(!bonding_curve.complete, CurveLaunchpadError::BondingCurveComplete);
+        require!(!bonding_curve.is_expired(curr_time), CurveLaunchpadError::BondingCurveExpired);
+        bonding_curve.check_trading_open(curr_time, is_allowlisted)?;
+
+        let mut amm = amm::amm::AMM::new(
+            bonding_curve.virtual_sol_reserves as u128,
+            bonding_curve.virtual_token_reserves as u128,
+            bonding_curve.real_sol_reserves as u128,
+            bonding_curve.real_token_reserves as u128,
+            ctx.accounts.global.initial_virtual_token_reserves as u128,
+        );
+
+        let signer: [&[&[u8]]; 1] = [&[
+            BondingCurve::SEED_PREFIX,
+            leg.mint.as_ref(),
+            &[bonding_curve_bump],
+        ]];
+
+        let fees = TradeFees {
+            fee_tier,
+            protocol_fee_basis_points,
+            creator_fee_basis_points: bonding_curve.creator_fee_basis_points(leg.is_buy, curr_time),
+            invite_fee_basis_points: ctx.accounts.global.invite_fee_basis_points,
+        };
+
+        let (sol_amount, token_amount, fee) = if leg.is_buy {
+            require!(leg.token_amount > 0, CurveLaunchpadError::MinBuy);
+            require!(
+                bonding_curve.real_token_reserves >= leg.token_amount,
+                CurveLaunchpadError::InsufficientTokens,
+            );
+
+            let target_token_amount = leg.token_amount.min(bonding_curve_token_account.amount);
+            let buy_result = amm
+                .apply_buy(target_token_amount as u128)
+                .ok_or(CurveLaunchpadError::InsufficientTokens)?;
+            let fee = fees.fee(buy_result.sol_amount);
+
+            require!(
+                buy_result.sol_amount + fee <= leg.limit,
+                CurveLaunchpadError::MaxSOLCostExceeded,
+            );
+            require!(
+                user.lamports() >= buy_result.sol_amount + fee + total_buy_fee,
+                CurveLaunchpadError::InsufficientSOL,
+            );
+
+            //transfer SOL to bonding curve
+            anchor_lang::solana_program::program::invoke(
+                &system_instruction::transfer(user.key, &bonding_curve_key, buy_result.sol_amount),
+                &[
+                    user.clone(),
+                    bonding_curve.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+
+            total_buy_fee += fee;
+
+            //transfer SPL
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: bonding_curve_token_account.to_account_info(),
+                        to: user_token_account.to_account_info(),
+                        authority: bonding_curve.to_account_info(),
+                    },
+                    &signer,
+                ),
+                buy_result.token_amount,
+            )?;
+
+            (buy_result.sol_amount, buy_result.token_amount, fee)
+        } else {
+            require!(leg.token_amount > 0, CurveLaunchpadError::MinSell);
+            require!(
+                user_token_account.amount >= leg.token_amount,
+                CurveLaunchpadError::InsufficientTokens,
+            );
+
+            let sell_result = amm
+                .apply_sell(leg.token_amount as u128)
+                .ok_or(CurveLaunchpadError::InsufficientSOL)?;
+            let fee = fees.fee(sell_result.sol_amount);
+            let sell_amount_minus_fee = sell_result
+                .sol_amount
+                .checked_sub(fee)
+                .ok_or(CurveLaunchpadError::MathOverflow)?;
+
+            require!(
+                sell_amount_minus_fee >= leg.limit,
+                CurveLaunchpadError::MinSOLOutputExceeded,
+            );
+
+            //transfer SPL
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: user_token_account.to_account_info(),
+                        to: bonding_curve_token_account.to_account_info(),
+                        authority: user.clone(),
+                    },
+                ),
+                sell_result.token_amount,
+            )?;
+
+            //transfer SOL back to user
+            **bonding_curve.to_account_info().try_borrow_mut_lamports()? -= sell_result.sol_amount;
+            **user.try_borrow_mut_lamports()? += sell_amount_minus_fee;
+            **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? += fee;
+
+            (sell_result.sol_amount, sell_result.token_amount, fee)
+        };
+
+        //apply the trade to the bonding curve
+        bonding_curve.real_token_reserves = amm.real_token_reserves as u64;
+        bonding_curve.real_sol_reserves = amm.real_sol_reserves as u64;
+        bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
+        bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
+        bonding_curve.update_time = curr_time as u64;
+        if bonding_curve.real_token_reserves == 0 {
+            bonding_curve.complete = true;
+        }
+
+        //the fee is recorded in the fee account once for the whole batch, each leg books its shares
+        book_trade_fee(
+            &ctx.accounts.global,
+            &fees,
+            sol_amount,
+            &mut bonding_curve,
+            &mut ctx.accounts.user_invite_account,
+            [
+                ctx.accounts.parent_invite_account.as_deref_mut(),
+                ctx.accounts.grandparent_invite_account.as_deref_mut(),
+                ctx.accounts.great_grandparent_invite_account.as_deref_mut(),
+            ],
+            &mut ctx.accounts.fee_recipient_invite_account,
+            program_id,
+        )?;
+        bonding_curve.exit(program_id)?;
+
+        total_fee += fee;
+        total_volume += sol_amount;
+
+        leg_events.push(BatchTradeLegEvent {
+            mint: leg.mint.to_string(),
+            is_buy: leg.is_buy,
+            sol_amount,
+            token_amount,
+            fee,
+            virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: bonding_curve.virtual_token_reserves,
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            real_token_reserves: bonding_curve.real_token_reserves,
+        });
+
+        if leg.is_buy && bonding_curve.real_token_reserves == 0 {
+            let complete_event = CompleteEvent {
+                user: user.key().to_string(),
+                mint: leg.mint.to_string(),
+                bonding_curve: bonding_curve_key.to_string(),
+                timestamp: curr_time,
+            };
+            let serialized = serde_json::to_string(&complete_event).unwrap();
+            msg!("completelog:{}", serialized);
+        }
+    }
+
+    //transfer the buy fees to the fee account
+    if total_buy_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &system_instruction::transfer(user.key, ctx.accounts.fee_account.to_account_info().key, total_buy_fee),
+            &[
+                user.clone(),
+                ctx.accounts.fee_account.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    ctx.accounts.fee_account.received += total_fee;
+    let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
+    require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+
+    let batch_trade_event = BatchTradeEvent {
+        user: user.key().to_string(),
+        legs: leg_events,
+        total_sol_amount: total_volume,
+        total_fee,
+        timestamp: curr_time,
+        hash: hash.to_string(),
+        fee_tier,
+    };
+
+    let serialized = serde_json::to_string(&batch_trade_event).unwrap();
+
+    msg!("batchtradelog:{}", serialized);
+
+    Ok(())
+}
+
+//a curve is loaded once per batch, a second leg on the same mint would trade against stale reserves
+pub fn has_unique_mints(legs: &[TradeLeg]) -> bool {
+    legs.iter()
+        .enumerate()
+        .all(|(i, leg)| legs[..i].iter().all(|other| other.mint != leg.mint))
+}
+
+//the program id marks a leg without an allowlist entry, as anchor does for optional accounts
+fn is_allowlisted<'info>(
+    info: &'info AccountInfo<'info>,
+    mint: &Pubkey,
+    user: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<bool> {
+    if info.key == program_id {
+        return Ok(false);
+    }
+
+    let (allowlist_entry_key, _) = Pubkey::find_program_address(
+        &[AllowlistEntry::SEED_PREFIX, mint.as_ref(), user.as_ref()],
+        program_id,
+    );
+    require_keys_eq!(info.key(), allowlist_entry_key, CurveLaunchpadError::InvalidBatchLegs);
+    Account::<AllowlistEntry>::try_from(info)?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leg(mint: Pubkey) -> TradeLeg {
+        TradeLeg {
+            mint,
+            is_buy: true,
+            token_amount: 1,
+            limit: 1,
+        }
+    }
+
+    #[test]
+    fn test_has_unique_mints() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        assert!(has_unique_mints(&[leg(a)]));
+        assert!(has_unique_mints(&[leg(a), leg(b)]));
+        assert!(!has_unique_mints(&[leg(a), leg(b), leg(a)]));
+        assert!(!has_unique_mints(&[leg(b), leg(b)]));
+    }
+}
@@ -5,9 +5,9 @@ use anchor_spl::{
 };
 
 use crate::{
-    amm, calculate_fee,
-    state::{BondingCurve, FeeAccount, Global, UserInviteStats},
-    CompleteEvent, CurveLaunchpadError, TradeEvent,
+    amm, is_within_deadline, is_within_price_limit, settle_trade_fee,
+    state::{AllowlistEntry, BondingCurve, FeeAccount, Global, UserInviteStats},
+    CompleteEvent, CurveLaunchpadError, TradeEvent, TradeFees,
 };
 
 #[derive(Accounts)]
@@ -53,26 +53,42 @@ pub struct Buy<'info> {
     )]
     user_token_account: Box<Account<'info, TokenAccount>>,
 
+    //only needed during the allowlist phase
     #[account(
-        mut,
-        seeds=[UserInviteStats::SEED_PREFIX,user_invite_account.parent.key().as_ref()],
-        bump
+        seeds = [AllowlistEntry::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump,
     )]
-    parent_invite_account: Box<Account<'info, UserInviteStats>>,
+    allowlist_entry: Option<Box<Account<'info, AllowlistEntry>>>,
 
     #[account(
-        mut,
-        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
         bump
     )]
-    fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    //referral chain, only needed when the trader has a referrer.
+    //validated against the chain in distribute_invite_fee
+    #[account(mut)]
+    parent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    great_grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
 
     #[account(
-        mut,
-        seeds=[UserInviteStats::SEED_PREFIX,bonding_curve.creator.key().as_ref()],
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
         bump
     )]
-    creator_fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
+    fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
+
 
 
     #[account(
@@ -83,18 +99,43 @@ pub struct Buy<'info> {
     fee_account : Box<Account<'info,FeeAccount>>,
 
     system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
 
 }
 
-pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64, hash: &str) -> Result<()> {
+pub fn buy(
+    ctx: Context<Buy>,
+    token_amount: u64,
+    max_sol_cost: u64,
+    hash: &str,
+    deadline: Option<i64>,
+    max_price: Option<u64>,
+) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+
+    //transaction has not expired
+    require!(
+        is_within_deadline(curr_time, deadline),
+        CurveLaunchpadError::DeadlineExceeded
+    );
+
     //bonding curve is not complete
     require!(
-        ctx.accounts.bonding_curve.complete == false,
+        !ctx.accounts.bonding_curve.complete,
         CurveLaunchpadError::BondingCurveComplete,
     );
 
-    require!(ctx.accounts.user_invite_account.is_init,CurveLaunchpadError::InviteAccountError);
+    //before the public start only allowlisted users can trade
+    ctx.accounts
+        .bonding_curve
+        .check_trading_open(curr_time, ctx.accounts.allowlist_entry.is_some())?;
+
+    //bonding curve has not expired
+    require!(
+        !ctx.accounts.bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::BondingCurveExpired,
+    );
 
     //bonding curve has enough tokens
     require!(
@@ -113,14 +154,30 @@ pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64, hash: &str)
     let mut amm = amm::amm::AMM::new(
         ctx.accounts.bonding_curve.virtual_sol_reserves as u128,
         ctx.accounts.bonding_curve.virtual_token_reserves as u128,
+        ctx.accounts.bonding_curve.real_sol_reserves as u128,
+        ctx.accounts.bonding_curve.real_token_reserves as u128,
         ctx.accounts.global.initial_virtual_token_reserves as u128,
     );
 
     let buy_result = amm.apply_buy(targe_token_amount as u128).unwrap();
-    let fee = calculate_fee(
-        buy_result.sol_amount,
-        ctx.accounts.global.fee_basis_points + ctx.accounts.global.creator_fee_basis_points + ctx.accounts.global.invite_fee_basis_points,
+
+    //check the marginal price after the buy is not above the max_price
+    let price_after = amm
+        .get_marginal_price()
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+    require!(
+        is_within_price_limit(price_after, max_price, true),
+        CurveLaunchpadError::PriceSlippageExceeded,
     );
+
+    let fees = TradeFees::new(
+        &ctx.accounts.global,
+        &ctx.accounts.bonding_curve,
+        &ctx.accounts.user_invite_account,
+        true,
+        curr_time,
+    );
+    let fee = fees.fee(buy_result.sol_amount);
     let buy_amount_with_fee = buy_result.sol_amount + fee;
 
 
@@ -171,20 +228,21 @@ pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64, hash: &str)
         &[],
     )?;
 
-    ctx.accounts.fee_account.received += fee;
-    require!(is_ok,CurveLaunchpadError::FeeAccountStatusAbnormal);
-
-    
-    let protocol_fee = calculate_fee(buy_result.sol_amount, ctx.accounts.global.fee_basis_points);
-    let creator_fee = calculate_fee(buy_result.sol_amount, ctx.accounts.global.creator_fee_basis_points);
-
-
-    ctx.accounts.fee_recipient_invite_account.profit_claimable += protocol_fee;
-    ctx.accounts.creator_fee_recipient_invite_account.profit_claimable += creator_fee;
-    ctx.accounts.parent_invite_account.profit_claimable += invite_fee;
-
-    ctx.accounts.user_invite_account.profit_to_parent += invite_fee;
-    ctx.accounts.parent_invite_account.profit_from_child += invite_fee;
+    settle_trade_fee(
+        &ctx.accounts.global,
+        &fees,
+        buy_result.sol_amount,
+        &mut ctx.accounts.bonding_curve,
+        &mut ctx.accounts.user_invite_account,
+        [
+            ctx.accounts.parent_invite_account.as_deref_mut(),
+            ctx.accounts.grandparent_invite_account.as_deref_mut(),
+            ctx.accounts.great_grandparent_invite_account.as_deref_mut(),
+        ],
+        &mut ctx.accounts.fee_recipient_invite_account,
+        &mut ctx.accounts.fee_account,
+        ctx.program_id,
+    )?;
 
 
 
@@ -196,11 +254,13 @@ pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64, hash: &str)
             .to_account_info()
             .clone(),
         to: ctx.accounts.user_token_account.to_account_info().clone(),
+        authority: ctx.accounts.bonding_curve.to_account_info().clone(),
     };
 
     let signer: [&[&[u8]]; 1] = [&[
         BondingCurve::SEED_PREFIX,
         ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
     ]];
 
     token::transfer(
@@ -219,18 +279,21 @@ pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64, hash: &str)
     bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
     bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
 
-    let curr_time = Clock::get()?.unix_timestamp;
     bonding_curve.update_time = curr_time as u64;
 
     let trade_event = TradeEvent {
         mint: ctx.accounts.mint.to_account_info().key().to_string(),
         sol_amount: buy_result.sol_amount,
         token_amount: buy_result.token_amount,
+        is_buy: true,
+        user: ctx.accounts.user.to_account_info().key().to_string(),
+        timestamp: curr_time,
         virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
         virtual_token_reserves: bonding_curve.virtual_token_reserves,
         real_sol_reserves: bonding_curve.real_sol_reserves,
         real_token_reserves: bonding_curve.real_token_reserves,
         hash: hash.to_string(),
+        fee_tier: fees.fee_tier,
     };
 
     let serialized = serde_json::to_string(&trade_event).unwrap();
@@ -238,6 +301,7 @@ pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64, hash: &str)
     msg!("tradelog:{}", serialized);
 
     if bonding_curve.real_token_reserves == 0 {
+        bonding_curve.complete = true;
 
         let complete_event = CompleteEvent {
             user: ctx.accounts.user.to_account_info().key().to_string(),
@@ -0,0 +1,353 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::{
+    amm, settle_trade_fee,
+    state::{BondingCurve, BuybackTreasury, FeeAccount, Global, UserInviteStats},
+    BuybackAndBurnEvent, CompleteEvent, CurveLaunchpadError, FundBuybackEvent, TradeFees, WithdrawBuybackEvent,
+};
+
+#[derive(Accounts)]
+pub struct FundBuyback<'info> {
+    #[account(mut)]
+    funder: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + BuybackTreasury::INIT_SPACE,
+        seeds = [BuybackTreasury::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    buyback_treasury: Box<Account<'info, BuybackTreasury>>,
+
+    system_program: Program<'info, System>,
+}
+
+//anyone may fund the treasury, an amount of 0 only creates it
+pub fn fund_buyback(ctx: Context<FundBuyback>, amount: u64) -> Result<()> {
+    ctx.accounts.buyback_treasury.mint = ctx.accounts.mint.key();
+
+    if amount > 0 {
+        let transfer_instruction = system_instruction::transfer(
+            ctx.accounts.funder.key,
+            ctx.accounts.buyback_treasury.to_account_info().key,
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &transfer_instruction,
+            &[
+                ctx.accounts.funder.to_account_info(),
+                ctx.accounts.buyback_treasury.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[],
+        )?;
+    }
+
+    let fund_event = FundBuybackEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        funder: ctx.accounts.funder.key().to_string(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&fund_event).unwrap();
+
+    msg!("fundBuyback:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuybackAndBurn<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(mut, address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [BuybackTreasury::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    buyback_treasury: Box<Account<'info, BuybackTreasury>>,
+
+    //the creator's invite account always exists, create initializes it
+    #[account(
+        mut,
+        seeds=[UserInviteStats::SEED_PREFIX,creator.key().as_ref()],
+        bump
+    )]
+    creator_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    //referral chain of the creator, only needed when the creator has a referrer.
+    //validated against the chain in distribute_invite_fee
+    #[account(mut)]
+    parent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    great_grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = creator,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+//buys with treasury sol and burns the tokens straight out of the curve vault. the buy pays the
+//same fees as a buy by the creator, except the creator fee which would flow back to the creator
+pub fn buyback_and_burn(ctx: Context<BuybackAndBurn>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let treasury_key = ctx.accounts.buyback_treasury.key();
+
+    require!(!ctx.accounts.bonding_curve.is_complete(), CurveLaunchpadError::BondingCurveComplete);
+    require!(
+        !ctx.accounts.bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::BondingCurveExpired
+    );
+    ctx.accounts.bonding_curve.check_trading_open(curr_time, false)?;
+
+    //creator fees routed to the treasury are swept in before spending
+    let routed_fees = if ctx.accounts.bonding_curve.creator_fee_recipient == treasury_key {
+        ctx.accounts.bonding_curve.creator_fee_claimable
+    } else {
+        0
+    };
+    if routed_fees > 0 {
+        ctx.accounts.bonding_curve.creator_fee_claimable = 0;
+        ctx.accounts.bonding_curve.creator_fee_claimed += routed_fees;
+
+        **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? -= routed_fees;
+        **ctx.accounts.buyback_treasury.to_account_info().try_borrow_mut_lamports()? += routed_fees;
+
+        ctx.accounts.fee_account.sent += routed_fees;
+        let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
+        require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+    }
+
+    let treasury_info = ctx.accounts.buyback_treasury.to_account_info();
+    let available = treasury_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(treasury_info.data_len()));
+    require!(sol_amount > 0 && sol_amount <= available, CurveLaunchpadError::InsufficientSOL);
+
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    let mut fees = TradeFees::new(
+        &ctx.accounts.global,
+        bonding_curve,
+        &ctx.accounts.creator_invite_account,
+        true,
+        curr_time,
+    );
+    fees.creator_fee_basis_points = 0;
+
+    let mut amm = amm::amm::AMM::new(
+        bonding_curve.virtual_sol_reserves as u128,
+        bonding_curve.virtual_token_reserves as u128,
+        bonding_curve.real_sol_reserves as u128,
+        bonding_curve.real_token_reserves as u128,
+        ctx.accounts.global.initial_virtual_token_reserves as u128,
+    );
+
+    let sol_for_curve = sol_amount as u128 * 10000 / (10000 + fees.total_basis_points()) as u128;
+    let buy_result = amm
+        .get_tokens_for_sol(sol_for_curve)
+        .filter(|tokens| *tokens > 0)
+        .and_then(|tokens| amm.apply_buy(tokens))
+        .ok_or(CurveLaunchpadError::InsufficientSOL)?;
+    require!(
+        buy_result.token_amount >= min_tokens_out,
+        CurveLaunchpadError::PriceSlippageExceeded
+    );
+
+    let fee = fees.fee(buy_result.sol_amount);
+    let spent = buy_result.sol_amount + fee;
+
+    **treasury_info.try_borrow_mut_lamports()? -= spent;
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? += buy_result.sol_amount;
+    **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? += fee;
+
+    settle_trade_fee(
+        &ctx.accounts.global,
+        &fees,
+        buy_result.sol_amount,
+        &mut ctx.accounts.bonding_curve,
+        &mut ctx.accounts.creator_invite_account,
+        [
+            ctx.accounts.parent_invite_account.as_deref_mut(),
+            ctx.accounts.grandparent_invite_account.as_deref_mut(),
+            ctx.accounts.great_grandparent_invite_account.as_deref_mut(),
+        ],
+        &mut ctx.accounts.fee_recipient_invite_account,
+        &mut ctx.accounts.fee_account,
+        ctx.program_id,
+    )?;
+
+    //burn SPL
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        buy_result.token_amount,
+    )?;
+
+    //the bought tokens leave the reserves exactly as in a buy, and the supply shrinks by the burn
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_token_reserves = amm.real_token_reserves as u64;
+    bonding_curve.real_sol_reserves = amm.real_sol_reserves as u64;
+    bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
+    bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
+    bonding_curve.burn_supply(buy_result.token_amount)?;
+    bonding_curve.update_time = curr_time as u64;
+
+    let buyback_treasury = &mut ctx.accounts.buyback_treasury;
+    buyback_treasury.total_spent += spent;
+    buyback_treasury.total_burned += buy_result.token_amount;
+
+    let buyback_event = BuybackAndBurnEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        sol_amount: buy_result.sol_amount,
+        fee,
+        token_amount: buy_result.token_amount,
+        routed_fees,
+        token_total_supply: bonding_curve.token_total_supply,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&buyback_event).unwrap();
+
+    msg!("buybackAndBurn:{}", serialized);
+
+    if bonding_curve.real_token_reserves == 0 {
+        bonding_curve.complete = true;
+        let complete_event = CompleteEvent {
+            user: ctx.accounts.creator.key().to_string(),
+            mint: ctx.accounts.mint.key().to_string(),
+            bonding_curve: ctx.accounts.bonding_curve.key().to_string(),
+            timestamp: curr_time,
+        };
+        let serialized = serde_json::to_string(&complete_event).unwrap();
+        msg!("completelog:{}", serialized);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBuyback<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [BuybackTreasury::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    buyback_treasury: Box<Account<'info, BuybackTreasury>>,
+}
+
+//buybacks stop once the curve completes or expires, the creator then takes the treasury back and closes it
+pub fn withdraw_buyback(ctx: Context<WithdrawBuyback>) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    require!(
+        bonding_curve.is_buyback_over(curr_time),
+        CurveLaunchpadError::BondingCurveNotComplete
+    );
+
+    //creator fees routed to the closed treasury are claimed by the creator again
+    if ctx.accounts.bonding_curve.creator_fee_recipient == ctx.accounts.buyback_treasury.key() {
+        ctx.accounts.bonding_curve.creator_fee_recipient = ctx.accounts.creator.key();
+    }
+
+    let withdraw_event = WithdrawBuybackEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        sol_amount: ctx.accounts.buyback_treasury.to_account_info().lamports(),
+        total_spent: ctx.accounts.buyback_treasury.total_spent,
+        total_burned: ctx.accounts.buyback_treasury.total_burned,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&withdraw_event).unwrap();
+
+    msg!("withdrawBuyback:{}", serialized);
+
+    Ok(())
+}
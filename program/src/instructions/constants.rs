@@ -1,4 +1,34 @@
 pub const DEFAULT_DECIMALS: u32 = 6;
-pub const DEFAULT_TOKEN_LAMPORTS: u64 = (10 as u64).pow(DEFAULT_DECIMALS);
+pub const DEFAULT_TOKEN_LAMPORTS: u64 = 10u64.pow(DEFAULT_DECIMALS);
 pub const DEFAULT_TOKEN_SUPPLY: u64 = 1_000_000_000 * DEFAULT_TOKEN_LAMPORTS;
+pub const DEFAULT_VIRTUAL_SOL_RESERVES: u64 = 30_000_000_000;
+pub const MAX_BATCH_LEGS: usize = 20;
+pub const MINT_SEED_POOL_CAPACITY: usize = 128;
+pub const MAX_MINT_VANITY_LEN: usize = 8;
+pub const MAX_LIMIT_ORDERS: usize = 32;
+pub const ORDER_FILL_BOUNTY_BASIS_POINTS: u64 = 10;
+pub const MAX_LIMIT_ORDERS_PER_OWNER: usize = 4;
+pub const MIN_LIMIT_ORDER_SOL: u64 = 10_000_000;
+pub const MIN_LIMIT_ORDER_TOKENS: u64 = DEFAULT_TOKEN_LAMPORTS;
+pub const LIMIT_ORDER_MIN_DOUBLING_ORDERS: usize = 4;
+pub const LIMIT_ORDER_TTL_SECONDS: i64 = 7 * SECONDS_PER_DAY as i64;
+pub const DCA_BOUNTY_BASIS_POINTS: u64 = 10;
+pub const MAX_PRESALE_ALLOCATION_BASIS_POINTS: u64 = 2000;
+pub const SECONDS_PER_DAY: u64 = 86_400;
+pub const VOLUME_WINDOW_DAYS: usize = 30;
+pub const MAX_FEE_TIERS: usize = 4;
+pub const MAX_INVITE_LEVELS: usize = 3;
+pub const MIN_INVITE_CODE_LEN: usize = 3;
+pub const MAX_INVITE_CODE_LEN: usize = 16;
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+pub const MIN_TELEGRAM_HANDLE_LEN: usize = 5;
+pub const MAX_TELEGRAM_HANDLE_LEN: usize = 32;
+pub const MAX_TWITTER_HANDLE_LEN: usize = 15;
+pub const MAX_DESCRIPTION_LEN: usize = 256;
+pub const MAX_SOCIAL_LINK_LEN: usize = 128;
+pub const MAX_PROFILE_URI_LEN: usize = 200;
+pub const INVITE_REBIND_GRACE_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+pub const EXPIRED_REDEEM_WINDOW_SECONDS: i64 = 30 * SECONDS_PER_DAY as i64;
 pub const WSOL_MINT_ADDRESS: &str = "So11111111111111111111111111111111111111112";
\ No newline at end of file
@@ -1,14 +1,18 @@
 use crate::{
-    state::{BondingCurve, Global, UserInviteStats}, CreateEvent, CurveLaunchpadError, DEFAULT_DECIMALS
+    amm, is_printable, is_valid_social_handle, is_valid_uri, settle_trade_fee, state::{BondingCurve, CreatorVesting, FeeAccount, FeeSchedule, Global, LaunchSchedule, PresaleConfig, TokenProfile, UserInviteStats}, CreateEvent, CurveLaunchpadError, TradeEvent, TradeFees, VestingParams, DEFAULT_DECIMALS, MAX_PRESALE_ALLOCATION_BASIS_POINTS,
+    MAX_DESCRIPTION_LEN, MAX_NAME_LEN, MAX_SOCIAL_LINK_LEN, MAX_SYMBOL_LEN, MAX_TELEGRAM_HANDLE_LEN,
+    MAX_TWITTER_HANDLE_LEN, MAX_URI_LEN, MIN_TELEGRAM_HANDLE_LEN,
 };
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::system_instruction};
 use anchor_spl::{
+    associated_token::AssociatedToken,
     metadata::{
         create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
         Metadata as Metaplex,
     },
     token::{
         self, mint_to, spl_token::instruction::AuthorityType, Mint, MintTo, Token, TokenAccount,
+        Transfer,
     },
 };
 
@@ -103,10 +107,78 @@ pub struct Create<'info> {
 
 
     #[account(
-        mut,
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = creator,
         seeds=[UserInviteStats::SEED_PREFIX,creator.key().as_ref()],
         bump
     )]
+    creator_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    //referral chain, only needed when the trader has a referrer.
+    //validated against the chain in distribute_invite_fee
+    #[account(mut)]
+    parent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    great_grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = creator,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account : Box<Account<'info,FeeAccount>>,
+
+    //only required for the initial buy
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+    )]
+    creator_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    //only required when the initial buy is locked into vesting
+    #[account(
+        init,
+        payer = creator,
+        seeds = [CreatorVesting::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+        space = 8 + CreatorVesting::INIT_SPACE,
+    )]
+    creator_vesting: Option<Box<Account<'info, CreatorVesting>>>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = creator_vesting,
+        seeds = [CreatorVesting::VAULT_SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    vesting_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [TokenProfile::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+        space = 8 + TokenProfile::INIT_SPACE,
+    )]
+    token_profile: Box<Account<'info, TokenProfile>>,
 
     system_program: Program<'info, System>,
 
@@ -122,7 +194,8 @@ pub struct Create<'info> {
 }
 
 
-pub fn create(ctx: Context<Create>,
+#[allow(clippy::too_many_arguments)]
+pub fn create(mut ctx: Context<Create>,
     name: String,
     symbol: String,
     uri: String,
@@ -130,6 +203,15 @@ pub fn create(ctx: Context<Create>,
     website: String,
     telegram: String,
     twitter: String,
+    initial_buy_sol: Option<u64>,
+    min_tokens: u64,
+    vesting: Option<VestingParams>,
+    buy_fee_schedule: Option<FeeSchedule>,
+    sell_fee_schedule: Option<FeeSchedule>,
+    expires_at: Option<i64>,
+    launch_schedule: Option<LaunchSchedule>,
+    presale: Option<PresaleConfig>,
+    auction_end_time: Option<i64>,
 ) -> Result<()> {
     //confirm program is initialized
     require!(
@@ -138,12 +220,32 @@ pub fn create(ctx: Context<Create>,
     );
 
 
-    // Verify that the mint address ends with "meme"
+    //curves reserved through the mint seed pool can only be launched by their creator
+    let reserved_by = ctx.accounts.bonding_curve.creator;
+    require!(
+        reserved_by == Pubkey::default() || reserved_by == ctx.accounts.creator.key(),
+        CurveLaunchpadError::MintReserved
+    );
+
+    validate_token_metadata(&name, &symbol, &uri)?;
+    validate_token_socials(&description, &website, &telegram, &twitter)?;
+
+    // Verify that the mint address satisfies the vanity rule
     require!(
-        ctx.accounts.mint.key().to_string().to_lowercase().ends_with("meme"),
+        ctx.accounts.global.mint_matches_vanity(&ctx.accounts.mint.key()),
         CurveLaunchpadError::InvalidMintAddress
     );
 
+    //per-curve creator fees default to the global rate and are bounded by the global max
+    let default_schedule = FeeSchedule::flat(ctx.accounts.global.creator_fee_basis_points);
+    let buy_fee_schedule = buy_fee_schedule.unwrap_or(default_schedule);
+    let sell_fee_schedule = sell_fee_schedule.unwrap_or(default_schedule);
+    require!(
+        buy_fee_schedule.max_basis_points() <= ctx.accounts.global.max_creator_fee_basis_points
+            && sell_fee_schedule.max_basis_points() <= ctx.accounts.global.max_creator_fee_basis_points,
+        CurveLaunchpadError::CreatorFeeTooHigh
+    );
+
     let signer: [&[&[u8]]; 1] = [&[
         BondingCurve::SEED_PREFIX,
         ctx.accounts.mint.to_account_info().key.as_ref(),
@@ -165,6 +267,8 @@ pub fn create(ctx: Context<Create>,
         CreateMetadataAccountsV3 {
             payer: ctx.accounts.creator.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
+            mint_authority: ctx.accounts.bonding_curve.to_account_info(),
+            update_authority: ctx.accounts.bonding_curve.to_account_info(),
             metadata: ctx.accounts.metadata.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
             rent: ctx.accounts.rent.to_account_info(),
@@ -172,7 +276,8 @@ pub fn create(ctx: Context<Create>,
         &signer,
     );
 
-    create_metadata_accounts_v3(metadata_ctx, token_data, false, true, None)?;
+    //the bonding curve keeps update authority so the creator can fix metadata before graduation
+    create_metadata_accounts_v3(metadata_ctx, token_data, true, true, None)?;
 
     //mint tokens to bonding_curve_token_account
     mint_to(
@@ -203,6 +308,7 @@ pub fn create(ctx: Context<Create>,
 
 
     let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.virtual_sol_reserves = ctx.accounts.global.initial_virtual_sol_reserves;
     bonding_curve.virtual_token_reserves = ctx.accounts.global.initial_virtual_token_reserves;
     bonding_curve.real_token_reserves = ctx.accounts.global.initial_real_token_reserves;
     bonding_curve.token_total_supply = ctx.accounts.global.initial_token_supply;
@@ -213,6 +319,121 @@ pub fn create(ctx: Context<Create>,
     bonding_curve.creator = ctx.accounts.creator.to_account_info().key();
     bonding_curve.mint = ctx.accounts.mint.to_account_info().key();
     bonding_curve.create_time = current_timestamp;
+    bonding_curve.buy_fee_schedule = buy_fee_schedule;
+    bonding_curve.sell_fee_schedule = sell_fee_schedule;
+    bonding_curve.creator_fee_recipient = ctx.accounts.creator.to_account_info().key();
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > current_timestamp as i64, CurveLaunchpadError::InvalidExpiry);
+        bonding_curve.expires_at = expires_at;
+    }
+
+    //the creator's initial buy is part of the launch and ignores the schedule
+    if let Some(launch_schedule) = launch_schedule {
+        require!(
+            launch_schedule.trading_start_time > current_timestamp as i64
+                && launch_schedule.allowlist_start_time < launch_schedule.trading_start_time
+                && launch_schedule.allowlist_start_time >= 0
+                && (bonding_curve.expires_at == 0 || bonding_curve.expires_at > launch_schedule.trading_start_time),
+            CurveLaunchpadError::InvalidLaunchSchedule
+        );
+        bonding_curve.launch_schedule = launch_schedule;
+    }
+
+    //public trading opens when the presale ends, a zero price sells at the opening price of the curve.
+    //presale tokens leave the real reserves only, so the curve completes earlier and at a lower price
+    if let Some(mut presale) = presale {
+        if bonding_curve.launch_schedule.trading_start_time == 0 {
+            bonding_curve.launch_schedule.trading_start_time = presale.end_time;
+        }
+        let schedule = bonding_curve.launch_schedule;
+        require!(
+            presale.merkle_root != [0; 32]
+                && presale.end_time > current_timestamp as i64
+                && schedule.trading_start_time >= presale.end_time
+                && (schedule.allowlist_start_time == 0 || schedule.allowlist_start_time >= presale.end_time)
+                && (bonding_curve.expires_at == 0 || bonding_curve.expires_at > presale.end_time)
+                && presale.allocation > 0
+                && presale.allocation as u128
+                    <= bonding_curve.real_token_reserves as u128 * MAX_PRESALE_ALLOCATION_BASIS_POINTS as u128 / 10000,
+            CurveLaunchpadError::InvalidPresale
+        );
+        presale.sold_tokens = 0;
+        presale.sol_raised = 0;
+
+        if presale.price == 0 {
+            let amm = amm::amm::AMM::new(
+                bonding_curve.virtual_sol_reserves as u128,
+                bonding_curve.virtual_token_reserves as u128,
+                bonding_curve.real_sol_reserves as u128,
+                bonding_curve.real_token_reserves as u128,
+                ctx.accounts.global.initial_virtual_token_reserves as u128,
+            );
+            presale.price = amm.get_marginal_price().ok_or(CurveLaunchpadError::InvalidPresale)? as u64;
+        }
+        require!(presale.price > 0, CurveLaunchpadError::InvalidPresale);
+        bonding_curve.presale = presale;
+    }
+
+    //the opening auction replaces the presale, public trading opens once it is settled
+    if let Some(auction_end_time) = auction_end_time {
+        if bonding_curve.launch_schedule.trading_start_time == 0 {
+            bonding_curve.launch_schedule.trading_start_time = auction_end_time;
+        }
+        let schedule = bonding_curve.launch_schedule;
+        require!(
+            presale.is_none()
+                && auction_end_time > current_timestamp as i64
+                && schedule.trading_start_time >= auction_end_time
+                && (schedule.allowlist_start_time == 0 || schedule.allowlist_start_time >= auction_end_time)
+                && (bonding_curve.expires_at == 0 || bonding_curve.expires_at > auction_end_time),
+            CurveLaunchpadError::InvalidAuction
+        );
+        bonding_curve.auction.commit_end_time = auction_end_time;
+    }
+
+    //image and banner are set later through update_profile
+    let token_profile = &mut ctx.accounts.token_profile;
+    token_profile.mint = ctx.accounts.mint.to_account_info().key();
+    token_profile.set_fields(
+        description.clone(),
+        website.clone(),
+        telegram.clone(),
+        twitter.clone(),
+        String::new(),
+        String::new(),
+    )?;
+    token_profile.update_time = current_timestamp;
+
+    if let Some(vesting) = &vesting {
+        require!(initial_buy_sol.is_some(), CurveLaunchpadError::InvalidVestingSchedule);
+        require!(
+            vesting.duration_seconds > 0 && vesting.cliff_seconds <= vesting.duration_seconds,
+            CurveLaunchpadError::InvalidVestingSchedule
+        );
+    }
+
+    let (initial_buy_sol_amount, initial_buy_token_amount) = match initial_buy_sol {
+        Some(sol_amount) => initial_buy(&mut ctx, sol_amount, min_tokens, vesting.is_some(), &signer)?,
+        None => (0, 0),
+    };
+
+    //lock the initial buy with a cliff and linear release
+    if let Some(vesting) = vesting {
+        let creator = ctx.accounts.creator.key();
+        let mint = ctx.accounts.mint.key();
+        let creator_vesting = ctx
+            .accounts
+            .creator_vesting
+            .as_mut()
+            .ok_or(CurveLaunchpadError::VestingAccountRequired)?;
+        creator_vesting.creator = creator;
+        creator_vesting.mint = mint;
+        creator_vesting.total_amount = initial_buy_token_amount;
+        creator_vesting.claimed_amount = 0;
+        creator_vesting.start_time = current_timestamp;
+        creator_vesting.cliff_time = current_timestamp + vesting.cliff_seconds;
+        creator_vesting.end_time = current_timestamp + vesting.duration_seconds;
+    }
 
 
     let create_event = CreateEvent {
@@ -220,6 +441,7 @@ pub fn create(ctx: Context<Create>,
         symbol:symbol.clone(),
         uri:uri.clone(),
         mint: ctx.accounts.mint.to_account_info().key().to_string(),
+        memecoin_config: ctx.accounts.global.to_account_info().key().to_string(),
         creator: ctx.accounts.creator.to_account_info().key().to_string(),
         created_time: current_timestamp,
         destination: ctx.accounts.bonding_curve_token_account.to_account_info().key().to_string(),
@@ -228,6 +450,13 @@ pub fn create(ctx: Context<Create>,
         telegram: telegram.clone(),
         twitter: twitter.clone(),
         decimal: 6,
+        initial_buy_sol_amount,
+        initial_buy_token_amount,
+        trading_start_time: ctx.accounts.bonding_curve.launch_schedule.trading_start_time,
+        allowlist_start_time: ctx.accounts.bonding_curve.launch_schedule.allowlist_start_time,
+        presale_end_time: ctx.accounts.bonding_curve.presale.end_time,
+        presale_price: ctx.accounts.bonding_curve.presale.price,
+        auction_end_time: ctx.accounts.bonding_curve.auction.commit_end_time,
     };
 
     //emit_cpi!(createEvent);
@@ -239,3 +468,295 @@ pub fn create(ctx: Context<Create>,
 
     Ok(())
 }
+
+
+//the creator's first purchase, executed against the fresh curve with the same fee and invite accounting as buy
+fn initial_buy(
+    ctx: &mut Context<Create>,
+    sol_amount: u64,
+    min_tokens: u64,
+    lock: bool,
+    signer: &[&[&[u8]]],
+) -> Result<(u64, u64)> {
+    //locked purchases go to the vesting vault instead of the creator
+    let destination = if lock {
+        ctx.accounts
+            .vesting_token_account
+            .as_ref()
+            .ok_or(CurveLaunchpadError::VestingAccountRequired)?
+            .to_account_info()
+    } else {
+        ctx.accounts
+            .creator_token_account
+            .as_ref()
+            .ok_or(CurveLaunchpadError::CreatorTokenAccountRequired)?
+            .to_account_info()
+    };
+
+    let mut amm = amm::amm::AMM::new(
+        ctx.accounts.bonding_curve.virtual_sol_reserves as u128,
+        ctx.accounts.bonding_curve.virtual_token_reserves as u128,
+        ctx.accounts.bonding_curve.real_sol_reserves as u128,
+        ctx.accounts.bonding_curve.real_token_reserves as u128,
+        ctx.accounts.global.initial_virtual_token_reserves as u128,
+    );
+
+    let buy_result = quote_initial_buy(&mut amm, sol_amount, min_tokens)?;
+
+    let fees = TradeFees::new(
+        &ctx.accounts.global,
+        &ctx.accounts.bonding_curve,
+        &ctx.accounts.creator_invite_account,
+        true,
+        ctx.accounts.clock.unix_timestamp,
+    );
+    let fee = fees.fee(buy_result.sol_amount);
+
+    //check if the creator has enough SOL
+    require!(
+        ctx.accounts.creator.lamports() >= buy_result.sol_amount + fee,
+        CurveLaunchpadError::InsufficientSOL,
+    );
+
+    // transfer SOL to bonding curve
+    anchor_lang::solana_program::program::invoke(
+        &system_instruction::transfer(
+            ctx.accounts.creator.key,
+            ctx.accounts.bonding_curve.to_account_info().key,
+            buy_result.sol_amount,
+        ),
+        &[
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.bonding_curve.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    //transfer SOL to fee account
+    anchor_lang::solana_program::program::invoke(
+        &system_instruction::transfer(
+            ctx.accounts.creator.key,
+            ctx.accounts.fee_account.to_account_info().key,
+            fee,
+        ),
+        &[
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.fee_account.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    settle_trade_fee(
+        &ctx.accounts.global,
+        &fees,
+        buy_result.sol_amount,
+        &mut ctx.accounts.bonding_curve,
+        &mut ctx.accounts.creator_invite_account,
+        [
+            ctx.accounts.parent_invite_account.as_deref_mut(),
+            ctx.accounts.grandparent_invite_account.as_deref_mut(),
+            ctx.accounts.great_grandparent_invite_account.as_deref_mut(),
+        ],
+        &mut ctx.accounts.fee_recipient_invite_account,
+        &mut ctx.accounts.fee_account,
+        ctx.program_id,
+    )?;
+
+    //transfer SPL
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                to: destination,
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            signer,
+        ),
+        buy_result.token_amount,
+    )?;
+
+    //apply the buy to the bonding curve
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_token_reserves = amm.real_token_reserves as u64;
+    bonding_curve.real_sol_reserves = amm.real_sol_reserves as u64;
+    bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
+    bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
+
+    let trade_event = TradeEvent {
+        mint: ctx.accounts.mint.to_account_info().key().to_string(),
+        sol_amount: buy_result.sol_amount,
+        token_amount: buy_result.token_amount,
+        is_buy: true,
+        user: ctx.accounts.creator.to_account_info().key().to_string(),
+        timestamp: ctx.accounts.clock.unix_timestamp,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+
+        hash: String::new(),
+        fee_tier: fees.fee_tier,
+    };
+
+    let serialized = serde_json::to_string(&trade_event).unwrap();
+
+    msg!("tradelog:{}", serialized);
+
+    Ok((buy_result.sol_amount, buy_result.token_amount))
+}
+
+
+//metaplex length limits plus a printable character and uri scheme policy
+pub fn validate_token_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= MAX_NAME_LEN,
+        CurveLaunchpadError::InvalidNameLength
+    );
+    require!(
+        !symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LEN,
+        CurveLaunchpadError::InvalidSymbolLength
+    );
+    require!(uri.len() <= MAX_URI_LEN, CurveLaunchpadError::UriTooLong);
+    require!(
+        is_printable(name, false) && is_printable(symbol, false),
+        CurveLaunchpadError::NonPrintableCharacters
+    );
+    require!(is_valid_uri(uri), CurveLaunchpadError::InvalidUriScheme);
+
+    Ok(())
+}
+
+//social fields are optional, empty strings are accepted
+pub fn validate_token_socials(description: &str, website: &str, telegram: &str, twitter: &str) -> Result<()> {
+    require!(
+        description.len() <= MAX_DESCRIPTION_LEN,
+        CurveLaunchpadError::DescriptionTooLong
+    );
+    require!(
+        is_printable(description, true),
+        CurveLaunchpadError::NonPrintableCharacters
+    );
+    require!(
+        website.is_empty()
+            || (website.len() <= MAX_SOCIAL_LINK_LEN && website.starts_with("https://") && is_valid_uri(website)),
+        CurveLaunchpadError::InvalidWebsite
+    );
+    require!(
+        telegram.is_empty()
+            || is_valid_social_handle(telegram, MIN_TELEGRAM_HANDLE_LEN, MAX_TELEGRAM_HANDLE_LEN)
+            || is_valid_social_link(telegram, &["https://t.me/"]),
+        CurveLaunchpadError::InvalidTelegram
+    );
+    require!(
+        twitter.is_empty()
+            || is_valid_social_handle(twitter, 1, MAX_TWITTER_HANDLE_LEN)
+            || is_valid_social_link(twitter, &["https://x.com/", "https://twitter.com/"]),
+        CurveLaunchpadError::InvalidTwitter
+    );
+
+    Ok(())
+}
+
+fn is_valid_social_link(link: &str, prefixes: &[&str]) -> bool {
+    link.len() <= MAX_SOCIAL_LINK_LEN
+        && is_valid_uri(link)
+        && prefixes.iter().any(|prefix| link.len() > prefix.len() && link.starts_with(prefix))
+}
+
+//applies the creator's initial buy to the fresh curve, spending at most sol_amount before fees
+fn quote_initial_buy(amm: &mut amm::amm::AMM, sol_amount: u64, min_tokens: u64) -> Result<amm::amm::BuyResult> {
+    let token_amount = amm
+        .get_tokens_for_sol(sol_amount as u128)
+        .ok_or(CurveLaunchpadError::MinBuy)?;
+    let buy_result = amm.apply_buy(token_amount).ok_or(CurveLaunchpadError::MinBuy)?;
+
+    require!(buy_result.token_amount > 0, CurveLaunchpadError::MinBuy);
+    require!(
+        buy_result.token_amount >= min_tokens,
+        CurveLaunchpadError::PriceSlippageExceeded,
+    );
+
+    Ok(buy_result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_VIRTUAL_SOL_RESERVES;
+
+    fn launch_amm() -> amm::amm::AMM {
+        amm::amm::AMM::new(
+            DEFAULT_VIRTUAL_SOL_RESERVES as u128,
+            1_075_000_000_000_000,
+            0,
+            793_100_000_000_000,
+            1_075_000_000_000_000,
+        )
+    }
+
+    #[test]
+    fn test_quote_initial_buy() {
+        let mut amm = launch_amm();
+        let buy_result = quote_initial_buy(&mut amm, 1_000_000_000, 0).unwrap();
+
+        assert!(buy_result.token_amount > 0);
+        assert!(buy_result.sol_amount <= 1_000_000_000);
+        assert_eq!(amm.real_sol_reserves, buy_result.sol_amount as u128);
+        assert_eq!(amm.real_token_reserves, 793_100_000_000_000 - buy_result.token_amount as u128);
+    }
+
+    #[test]
+    fn test_quote_initial_buy_slippage() {
+        let quoted = quote_initial_buy(&mut launch_amm(), 1_000_000_000, 0).unwrap().token_amount;
+
+        assert!(quote_initial_buy(&mut launch_amm(), 1_000_000_000, quoted).is_ok());
+        assert_eq!(
+            quote_initial_buy(&mut launch_amm(), 1_000_000_000, quoted + 1).unwrap_err(),
+            CurveLaunchpadError::PriceSlippageExceeded.into()
+        );
+        assert_eq!(
+            quote_initial_buy(&mut launch_amm(), 0, 0).unwrap_err(),
+            CurveLaunchpadError::MinBuy.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_token_metadata() {
+        assert!(validate_token_metadata("Meme", "MEME", "ipfs://meme").is_ok());
+        assert_eq!(
+            validate_token_metadata("", "MEME", "ipfs://meme").unwrap_err(),
+            CurveLaunchpadError::InvalidNameLength.into()
+        );
+        assert_eq!(
+            validate_token_metadata("Meme", &"M".repeat(MAX_SYMBOL_LEN + 1), "ipfs://meme").unwrap_err(),
+            CurveLaunchpadError::InvalidSymbolLength.into()
+        );
+        assert_eq!(
+            validate_token_metadata("Me\u{0}me", "MEME", "ipfs://meme").unwrap_err(),
+            CurveLaunchpadError::NonPrintableCharacters.into()
+        );
+        assert_eq!(
+            validate_token_metadata("Meme", "MEME", "http://meme").unwrap_err(),
+            CurveLaunchpadError::InvalidUriScheme.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_token_socials() {
+        assert!(validate_token_socials("", "", "", "").is_ok());
+        assert!(validate_token_socials("gm\ngm", "https://meme.xyz", "@meme_chat", "https://x.com/meme").is_ok());
+        assert_eq!(
+            validate_token_socials("", "http://meme.xyz", "", "").unwrap_err(),
+            CurveLaunchpadError::InvalidWebsite.into()
+        );
+        assert_eq!(
+            validate_token_socials("", "", "meme", "").unwrap_err(),
+            CurveLaunchpadError::InvalidTelegram.into()
+        );
+        assert_eq!(
+            validate_token_socials("", "", "", "https://meme.xyz/meme").unwrap_err(),
+            CurveLaunchpadError::InvalidTwitter.into()
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    state::{BondingCurve, FeeAccount, Global, UserInviteStats},
+    ClaimCreatorFeesEvent, CurveLaunchpadError, RenounceCreatorFeesEvent,
+    SetCreatorFeeRecipientEvent,
+};
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    //the creator or the current fee recipient
+    claimer: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    ///CHECK: must be the recipient recorded on the bonding curve
+    #[account(
+        mut,
+        address = bonding_curve.creator_fee_recipient
+    )]
+    recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+}
+
+pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let claimer = ctx.accounts.claimer.key();
+    let claim_amount = ctx.accounts.bonding_curve.claim_creator_fees(claimer)?;
+
+    **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? -= claim_amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += claim_amount;
+
+    ctx.accounts.fee_account.sent += claim_amount;
+    let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
+    require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+
+    let claim_event = ClaimCreatorFeesEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        claimer: claimer.to_string(),
+        recipient: ctx.accounts.recipient.key().to_string(),
+        amount: claim_amount,
+        claimed_amount: ctx.accounts.bonding_curve.creator_fee_claimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&claim_event).unwrap();
+
+    msg!("claimCreatorFees:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCreatorFeeRecipient<'info> {
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+pub fn set_creator_fee_recipient(ctx: Context<SetCreatorFeeRecipient>, recipient: Pubkey) -> Result<()> {
+    require!(
+        !ctx.accounts.bonding_curve.creator_fee_renounced,
+        CurveLaunchpadError::CreatorFeesRenounced
+    );
+    require!(recipient != Pubkey::default(), CurveLaunchpadError::InvalidCreatorFeeRecipient);
+
+    let old_recipient = ctx.accounts.bonding_curve.creator_fee_recipient;
+    ctx.accounts.bonding_curve.creator_fee_recipient = recipient;
+
+    let set_event = SetCreatorFeeRecipientEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        old_recipient: old_recipient.to_string(),
+        new_recipient: recipient.to_string(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&set_event).unwrap();
+
+    msg!("setCreatorFeeRecipient:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenounceCreatorFees<'info> {
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds=[UserInviteStats::SEED_PREFIX, global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+}
+
+//renouncing is permanent, unclaimed and future creator fees go to the protocol
+pub fn renounce_creator_fees(ctx: Context<RenounceCreatorFees>) -> Result<()> {
+    let unclaimed = ctx.accounts.bonding_curve.renounce_creator_fees()?;
+    ctx.accounts.fee_recipient_invite_account.profit_claimable += unclaimed;
+
+    let renounce_event = RenounceCreatorFeesEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        unclaimed_amount: unclaimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&renounce_event).unwrap();
+
+    msg!("renounceCreatorFees:{}", serialized);
+
+    Ok(())
+}
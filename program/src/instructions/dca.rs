@@ -0,0 +1,427 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    amm, calculate_fee, settle_trade_fee,
+    state::{BondingCurve, DcaVault, FeeAccount, Global, UserInviteStats},
+    CancelDcaEvent, CompleteEvent, CurveLaunchpadError, ExecuteDcaEvent, OpenDcaEvent, TradeFees,
+    DCA_BOUNTY_BASIS_POINTS,
+};
+
+#[derive(Accounts)]
+pub struct OpenDca<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + DcaVault::INIT_SPACE,
+        seeds = [DcaVault::SEED_PREFIX, mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    dca_vault: Box<Account<'info, DcaVault>>,
+
+    //created up front so the crank never pays for it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = owner,
+        seeds=[UserInviteStats::SEED_PREFIX,owner.key().as_ref()],
+        bump
+    )]
+    owner_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn open_dca(
+    ctx: Context<OpenDca>,
+    total_sol: u64,
+    amount_per_cycle: u64,
+    interval_seconds: u64,
+    min_tokens_per_cycle: u64,
+) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+
+    require!(!ctx.accounts.bonding_curve.complete, CurveLaunchpadError::BondingCurveComplete);
+    require!(
+        total_sol > 0 && amount_per_cycle > 0 && amount_per_cycle <= total_sol && interval_seconds > 0,
+        CurveLaunchpadError::InvalidDca
+    );
+
+    let transfer_instruction = system_instruction::transfer(
+        ctx.accounts.owner.key,
+        ctx.accounts.dca_vault.to_account_info().key,
+        total_sol,
+    );
+    anchor_lang::solana_program::program::invoke_signed(
+        &transfer_instruction,
+        &[
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.dca_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[],
+    )?;
+
+    let dca_vault = &mut ctx.accounts.dca_vault;
+    dca_vault.owner = ctx.accounts.owner.key();
+    dca_vault.mint = ctx.accounts.mint.key();
+    dca_vault.interval_seconds = interval_seconds;
+    dca_vault.amount_per_cycle = amount_per_cycle;
+    dca_vault.min_tokens_per_cycle = min_tokens_per_cycle;
+    dca_vault.remaining_sol = total_sol;
+    dca_vault.next_execution_time = curr_time;
+    dca_vault.cycles_executed = 0;
+
+    let open_event = OpenDcaEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.owner.key().to_string(),
+        total_sol,
+        amount_per_cycle,
+        interval_seconds,
+        min_tokens_per_cycle,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&open_event).unwrap();
+
+    msg!("openDca:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDca<'info> {
+    #[account(mut)]
+    cranker: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [DcaVault::SEED_PREFIX, mint.key().as_ref(), dca_vault.owner.as_ref()],
+        bump,
+    )]
+    dca_vault: Box<Account<'info, DcaVault>>,
+
+    ///CHECK: receives the remainder and the vault rent once the remainder can't buy a token
+    #[account(mut, address = dca_vault.owner)]
+    owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = dca_vault.owner,
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds=[UserInviteStats::SEED_PREFIX,dca_vault.owner.as_ref()],
+        bump
+    )]
+    owner_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    //referral chain of the owner, only needed when the owner has a referrer.
+    //validated against the chain in distribute_invite_fee
+    #[account(mut)]
+    parent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    great_grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = cranker,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+//permissionless crank, one cycle per call. the cycle pays the same fees as a buy by the owner,
+//plus a bounty for the cranker, all out of the cycle amount
+pub fn execute_dca(ctx: Context<ExecuteDca>) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    let dca_vault = &ctx.accounts.dca_vault;
+
+    require!(
+        curr_time >= dca_vault.next_execution_time,
+        CurveLaunchpadError::DcaNotDue
+    );
+    require!(dca_vault.remaining_sol > 0, CurveLaunchpadError::InvalidDca);
+    require!(!bonding_curve.complete, CurveLaunchpadError::BondingCurveComplete);
+    require!(!bonding_curve.is_expired(curr_time), CurveLaunchpadError::BondingCurveExpired);
+    bonding_curve.check_trading_open(curr_time, false)?;
+
+    let fees = TradeFees::new(
+        &ctx.accounts.global,
+        bonding_curve,
+        &ctx.accounts.owner_invite_account,
+        true,
+        curr_time,
+    );
+
+    //the last cycle may be partial, its minimum output shrinks in proportion
+    let cycle_sol = dca_vault.amount_per_cycle.min(dca_vault.remaining_sol);
+    let min_tokens = (dca_vault.min_tokens_per_cycle as u128 * cycle_sol as u128
+        / dca_vault.amount_per_cycle as u128) as u64;
+
+    let mut amm = amm::amm::AMM::new(
+        bonding_curve.virtual_sol_reserves as u128,
+        bonding_curve.virtual_token_reserves as u128,
+        bonding_curve.real_sol_reserves as u128,
+        bonding_curve.real_token_reserves as u128,
+        ctx.accounts.global.initial_virtual_token_reserves as u128,
+    );
+
+    let buy_result = match quote_cycle(&mut amm, cycle_sol, fees.total_basis_points()) {
+        Some(buy_result) => buy_result,
+        //a remainder too small for a single token goes back to the owner with the vault rent
+        None if cycle_sol == dca_vault.remaining_sol => {
+            let cancel_event = CancelDcaEvent {
+                mint: ctx.accounts.mint.key().to_string(),
+                user: dca_vault.owner.to_string(),
+                remaining_sol: dca_vault.remaining_sol,
+                cycles_executed: dca_vault.cycles_executed,
+                timestamp: curr_time,
+            };
+            ctx.accounts.dca_vault.close(ctx.accounts.owner.to_account_info())?;
+
+            let serialized = serde_json::to_string(&cancel_event).unwrap();
+            msg!("cancelDca:{}", serialized);
+
+            return Ok(());
+        }
+        None => return err!(CurveLaunchpadError::InsufficientSOL),
+    };
+    require!(
+        buy_result.token_amount >= min_tokens,
+        CurveLaunchpadError::PriceSlippageExceeded
+    );
+
+    let fee = fees.fee(buy_result.sol_amount);
+    let bounty = calculate_fee(buy_result.sol_amount, DCA_BOUNTY_BASIS_POINTS);
+    let spent = buy_result.sol_amount + fee + bounty;
+
+    **ctx.accounts.dca_vault.to_account_info().try_borrow_mut_lamports()? -= spent;
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? += buy_result.sol_amount;
+    **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? += fee;
+    **ctx.accounts.cranker.try_borrow_mut_lamports()? += bounty;
+
+    settle_trade_fee(
+        &ctx.accounts.global,
+        &fees,
+        buy_result.sol_amount,
+        &mut ctx.accounts.bonding_curve,
+        &mut ctx.accounts.owner_invite_account,
+        [
+            ctx.accounts.parent_invite_account.as_deref_mut(),
+            ctx.accounts.grandparent_invite_account.as_deref_mut(),
+            ctx.accounts.great_grandparent_invite_account.as_deref_mut(),
+        ],
+        &mut ctx.accounts.fee_recipient_invite_account,
+        &mut ctx.accounts.fee_account,
+        ctx.program_id,
+    )?;
+
+    //transfer SPL
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        buy_result.token_amount,
+    )?;
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_token_reserves = amm.real_token_reserves as u64;
+    bonding_curve.real_sol_reserves = amm.real_sol_reserves as u64;
+    bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
+    bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
+    bonding_curve.update_time = curr_time as u64;
+
+    if bonding_curve.real_token_reserves == 0 {
+        bonding_curve.complete = true;
+        let complete_event = CompleteEvent {
+            user: ctx.accounts.cranker.key().to_string(),
+            mint: ctx.accounts.mint.key().to_string(),
+            bonding_curve: ctx.accounts.bonding_curve.key().to_string(),
+            timestamp: curr_time,
+        };
+        let serialized = serde_json::to_string(&complete_event).unwrap();
+        msg!("completelog:{}", serialized);
+    }
+
+    let dca_vault = &mut ctx.accounts.dca_vault;
+    dca_vault.remaining_sol -= spent;
+    dca_vault.next_execution_time = curr_time + dca_vault.interval_seconds as i64;
+    dca_vault.cycles_executed += 1;
+
+    let execute_event = ExecuteDcaEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: dca_vault.owner.to_string(),
+        cranker: ctx.accounts.cranker.key().to_string(),
+        token_amount: buy_result.token_amount,
+        sol_amount: buy_result.sol_amount,
+        fee,
+        bounty,
+        remaining_sol: dca_vault.remaining_sol,
+        cycles_executed: dca_vault.cycles_executed,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&execute_event).unwrap();
+
+    msg!("executeDca:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelDca<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(address = dca_vault.mint)]
+    mint: Account<'info, Mint>,
+
+    //closing returns the remaining sol together with the rent
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+        seeds = [DcaVault::SEED_PREFIX, mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    dca_vault: Box<Account<'info, DcaVault>>,
+}
+
+pub fn cancel_dca(ctx: Context<CancelDca>) -> Result<()> {
+    let cancel_event = CancelDcaEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.owner.key().to_string(),
+        remaining_sol: ctx.accounts.dca_vault.remaining_sol,
+        cycles_executed: ctx.accounts.dca_vault.cycles_executed,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&cancel_event).unwrap();
+
+    msg!("cancelDca:{}", serialized);
+
+    Ok(())
+}
+
+//the buy of one cycle, its sol also pays the fees and the bounty. none when it can't buy a single token
+fn quote_cycle(amm: &mut amm::amm::AMM, cycle_sol: u64, fee_basis_points: u64) -> Option<amm::amm::BuyResult> {
+    let sol_for_curve = cycle_sol as u128 * 10000 / (10000 + fee_basis_points + DCA_BOUNTY_BASIS_POINTS) as u128;
+    amm.get_tokens_for_sol(sol_for_curve)
+        .filter(|tokens| *tokens > 0)
+        .and_then(|tokens| amm.apply_buy(tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_VIRTUAL_SOL_RESERVES;
+
+    fn launch_amm() -> amm::amm::AMM {
+        amm::amm::AMM::new(
+            DEFAULT_VIRTUAL_SOL_RESERVES as u128,
+            1_075_000_000_000_000,
+            0,
+            793_100_000_000_000,
+            1_075_000_000_000_000,
+        )
+    }
+
+    #[test]
+    fn test_quote_cycle() {
+        let mut amm = launch_amm();
+        let buy_result = quote_cycle(&mut amm, 100_000_000, 100).unwrap();
+        assert!(buy_result.token_amount > 0);
+        //the fees and the bounty are left out of the curve
+        let fee = calculate_fee(buy_result.sol_amount, 100 + DCA_BOUNTY_BASIS_POINTS);
+        assert!(buy_result.sol_amount + fee <= 100_000_000);
+        assert_eq!(amm.real_sol_reserves, buy_result.sol_amount as u128);
+
+        //a remainder worth less than a token buys nothing
+        let mut amm = launch_amm();
+        assert!(quote_cycle(&mut amm, 1, 100).is_none());
+        assert_eq!(amm.real_sol_reserves, 0);
+    }
+}
@@ -44,5 +44,123 @@ pub enum CurveLaunchpadError {
 
     #[msg("Invite account error.")]
     InviteAccountError,
-    
+
+    #[msg("Transaction deadline exceeded.")]
+    DeadlineExceeded,
+    #[msg("Price slippage exceeded.")]
+    PriceSlippageExceeded,
+    #[msg("Invalid batch trade legs.")]
+    InvalidBatchLegs,
+    #[msg("Creator token account is required for the initial buy.")]
+    CreatorTokenAccountRequired,
+    #[msg("Invalid vesting schedule.")]
+    InvalidVestingSchedule,
+    #[msg("Vesting accounts are required to lock the initial buy.")]
+    VestingAccountRequired,
+    #[msg("There are no vested tokens to claim.")]
+    NoVestedTokens,
+    #[msg("Creator fee exceeds the global maximum.")]
+    CreatorFeeTooHigh,
+    #[msg("Invalid fee tiers.")]
+    InvalidFeeTiers,
+    #[msg("Invalid invite levels.")]
+    InvalidInviteLevels,
+    #[msg("Invite code must be 3 to 16 upper case letters or digits.")]
+    InvalidInviteCode,
+    #[msg("Invite binding would create a cycle.")]
+    InviteCycle,
+    #[msg("Invite account can no longer be rebound.")]
+    InviteRebindNotAllowed,
+    #[msg("Signer is neither the owner nor the claim delegate.")]
+    InvalidClaimer,
+    #[msg("Creator fees have been renounced.")]
+    CreatorFeesRenounced,
+    #[msg("Invalid creator fee recipient.")]
+    InvalidCreatorFeeRecipient,
+    #[msg("Signer is not the pending creator.")]
+    InvalidPendingCreator,
+    #[msg("Token profile field is too long.")]
+    ProfileFieldTooLong,
+    #[msg("Token name must be 1 to 32 bytes.")]
+    InvalidNameLength,
+    #[msg("Token symbol must be 1 to 10 bytes.")]
+    InvalidSymbolLength,
+    #[msg("Token uri must be at most 200 bytes.")]
+    UriTooLong,
+    #[msg("Token uri must use https, ipfs or ar.")]
+    InvalidUriScheme,
+    #[msg("Description must be at most 256 bytes.")]
+    DescriptionTooLong,
+    #[msg("Text contains non printable characters.")]
+    NonPrintableCharacters,
+    #[msg("Website must be an https link.")]
+    InvalidWebsite,
+    #[msg("Telegram must be a handle or a t.me link.")]
+    InvalidTelegram,
+    #[msg("Twitter must be a handle or an x.com link.")]
+    InvalidTwitter,
+    #[msg("Mint seed pool is full.")]
+    MintSeedPoolFull,
+    #[msg("Invalid mint seed index.")]
+    InvalidMintSeedIndex,
+    #[msg("Mint is reserved for another creator.")]
+    MintReserved,
+    #[msg("Mint vanity pattern must be at most 8 base58 characters.")]
+    InvalidMintVanityRule,
+    #[msg("Expiry must be in the future.")]
+    InvalidExpiry,
+    #[msg("Bonding curve has expired.")]
+    BondingCurveExpired,
+    #[msg("Bonding curve has not expired.")]
+    BondingCurveNotExpired,
+    #[msg("Holders can still redeem the expired curve.")]
+    RedeemWindowOpen,
+    #[msg("Invalid launch schedule.")]
+    InvalidLaunchSchedule,
+    #[msg("Trading has not started.")]
+    TradingNotStarted,
+    #[msg("Only allowlisted users can trade before the public start.")]
+    NotAllowlisted,
+    #[msg("Invalid presale.")]
+    InvalidPresale,
+    #[msg("Presale is not active.")]
+    PresaleNotActive,
+    #[msg("Invalid presale proof.")]
+    InvalidPresaleProof,
+    #[msg("Presale allocation exceeded.")]
+    PresaleAllocationExceeded,
+    #[msg("Invalid auction.")]
+    InvalidAuction,
+    #[msg("Auction is not accepting commitments.")]
+    AuctionNotActive,
+    #[msg("Auction commit window has not ended.")]
+    AuctionNotEnded,
+    #[msg("Auction is already settled.")]
+    AuctionAlreadySettled,
+    #[msg("Auction is not settled.")]
+    AuctionNotSettled,
+    #[msg("Auction commitment is already claimed.")]
+    AuctionAlreadyClaimed,
+    #[msg("Invalid limit order.")]
+    InvalidLimitOrder,
+    #[msg("Order book is full.")]
+    OrderBookFull,
+    #[msg("Limit order not found.")]
+    LimitOrderNotFound,
+    #[msg("Invalid DCA parameters.")]
+    InvalidDca,
+    #[msg("DCA cycle is not due yet.")]
+    DcaNotDue,
+    #[msg("Fee parameters out of bounds.")]
+    InvalidFeeParams,
+    #[msg("Arithmetic overflow.")]
+    MathOverflow,
+    #[msg("Too many open limit orders.")]
+    TooManyLimitOrders,
+    #[msg("Limit order can't be evicted yet.")]
+    LimitOrderNotEvictable,
+    #[msg("Account is already migrated.")]
+    AlreadyMigrated,
+    #[msg("Expired curve is already closed.")]
+    ExpiredCurveClosed,
 }
\ No newline at end of file
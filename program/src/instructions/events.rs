@@ -17,6 +17,15 @@ pub struct CreateEvent {
     pub telegram : String,
     pub twitter: String,
     pub decimal: u8,
+
+    pub initial_buy_sol_amount: u64,
+    pub initial_buy_token_amount: u64,
+
+    pub trading_start_time: i64,
+    pub allowlist_start_time: i64,
+    pub presale_end_time: i64,
+    pub presale_price: u64,
+    pub auction_end_time: i64,
 }
 
 
@@ -36,6 +45,32 @@ pub struct TradeEvent {
     pub real_token_reserves: u64,
 
     pub hash : String,
+    pub fee_tier: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BatchTradeLegEvent {
+    pub mint: String,
+    pub is_buy: bool,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub fee: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BatchTradeEvent {
+    pub user: String,
+    pub legs: Vec<BatchTradeLegEvent>,
+    pub total_sol_amount: u64,
+    pub total_fee: u64,
+    pub timestamp: i64,
+
+    pub hash : String,
+    pub fee_tier: u8,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -58,9 +93,323 @@ pub struct SetParamsEvent {
 }
 
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClaimVestedEvent {
+    pub creator: String,
+    pub mint: String,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RegisterInviteCodeEvent {
+    pub user: String,
+    pub code: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RebindInviteEvent {
+    pub user: String,
+    pub old_parent: String,
+    pub new_parent: String,
+    pub timestamp: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ClaimInviteProfitEvent {
     pub user: String,
     pub amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+    pub claimer: String,
+    pub destination: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClaimCreatorFeesEvent {
+    pub mint: String,
+    pub claimer: String,
+    pub recipient: String,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetCreatorFeeRecipientEvent {
+    pub mint: String,
+    pub old_recipient: String,
+    pub new_recipient: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RenounceCreatorFeesEvent {
+    pub mint: String,
+    pub creator: String,
+    pub unclaimed_amount: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransferCreatorEvent {
+    pub mint: String,
+    pub creator: String,
+    pub pending_creator: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AcceptCreatorEvent {
+    pub mint: String,
+    pub old_creator: String,
+    pub new_creator: String,
+    pub creator_fee_recipient: String,
+    pub creator_fee_claimable: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateProfileEvent {
+    pub mint: String,
+    pub creator: String,
+    pub description: String,
+    pub website: String,
+    pub telegram: String,
+    pub twitter: String,
+    pub image_uri: String,
+    pub banner_uri: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateTokenMetadataEvent {
+    pub mint: String,
+    pub creator: String,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FreezeTokenMetadataEvent {
+    pub mint: String,
+    pub user: String,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddMintSeedsEvent {
+    pub count: u64,
+    pub pool_size: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RemoveMintSeedEvent {
+    pub seed: u64,
+    pub pool_size: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReserveMintSeedEvent {
+    pub creator: String,
+    pub mint: String,
+    pub seed: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RedeemExpiredEvent {
+    pub mint: String,
+    pub user: String,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub real_sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CloseExpiredCurveEvent {
+    pub mint: String,
+    pub creator: String,
+    pub sol_amount: u64,
+    pub burned_token_amount: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AllowlistEvent {
+    pub mint: String,
+    pub user: String,
+    pub allowed: bool,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PresaleBuyEvent {
+    pub mint: String,
+    pub user: String,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub fee: u64,
+    pub claimed_tokens: u64,
+    pub max_tokens: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CommitAuctionEvent {
+    pub mint: String,
+    pub user: String,
+    pub sol_amount: u64,
+    pub user_committed: u64,
+    pub total_committed: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SettleAuctionEvent {
+    pub mint: String,
+    pub total_committed: u64,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub fee: u64,
+    pub refund_sol: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClaimAuctionEvent {
+    pub mint: String,
+    pub user: String,
+    pub sol_committed: u64,
+    pub token_amount: u64,
+    pub refund_sol: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PlaceLimitOrderEvent {
+    pub mint: String,
+    pub user: String,
+    pub order_id: u64,
+    pub is_buy: bool,
+    pub amount: u64,
+    pub limit_price: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CancelLimitOrderEvent {
+    pub mint: String,
+    pub user: String,
+    pub order_id: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EvictLimitOrderEvent {
+    pub mint: String,
+    pub user: String,
+    pub evictor: String,
+    pub order_id: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FillLimitOrderEvent {
+    pub mint: String,
+    pub user: String,
+    pub cranker: String,
+    pub order_id: u64,
+    pub is_buy: bool,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub fee: u64,
+    pub bounty: u64,
+    //sol paid out to the owner, the refund for a buy and the proceeds for a sell
+    pub owner_proceeds: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OpenDcaEvent {
+    pub mint: String,
+    pub user: String,
+    pub total_sol: u64,
+    pub amount_per_cycle: u64,
+    pub interval_seconds: u64,
+    pub min_tokens_per_cycle: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExecuteDcaEvent {
+    pub mint: String,
+    pub user: String,
+    pub cranker: String,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub fee: u64,
+    pub bounty: u64,
+    pub remaining_sol: u64,
+    pub cycles_executed: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CancelDcaEvent {
+    pub mint: String,
+    pub user: String,
+    pub remaining_sol: u64,
+    pub cycles_executed: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FundBuybackEvent {
+    pub mint: String,
+    pub funder: String,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BuybackAndBurnEvent {
+    pub mint: String,
+    pub creator: String,
+    pub sol_amount: u64,
+    pub fee: u64,
+    pub token_amount: u64,
+    pub routed_fees: u64,
+    pub token_total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WithdrawBuybackEvent {
+    pub mint: String,
+    pub creator: String,
+    pub sol_amount: u64,
+    pub total_spent: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WithdrawEvent {
+    pub mint: String,
+    pub withdraw_authority: String,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount};
+
+use crate::{
+    state::BondingCurve, CloseExpiredCurveEvent, CurveLaunchpadError, RedeemExpiredEvent,
+    EXPIRED_REDEEM_WINDOW_SECONDS,
+};
+
+#[derive(Accounts)]
+pub struct RedeemExpired<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        mut,
+        address = bonding_curve.mint.key()
+    )]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    token_program: Program<'info, Token>,
+}
+
+//burns the tokens for their share of the sol left in the curve
+pub fn redeem_expired(ctx: Context<RedeemExpired>, token_amount: u64) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        ctx.accounts.bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::BondingCurveNotExpired
+    );
+    require!(token_amount > 0, CurveLaunchpadError::MinSell);
+    require!(
+        ctx.accounts.user_token_account.amount >= token_amount,
+        CurveLaunchpadError::InsufficientTokens
+    );
+
+    //every token outside the curve vault, and every auction token not claimed yet, has an equal claim on the real sol reserves
+    let circulating_supply = ctx
+        .accounts
+        .mint
+        .supply
+        .checked_sub(ctx.accounts.bonding_curve_token_account.amount)
+        .and_then(|supply| supply.checked_add(ctx.accounts.bonding_curve.unclaimed_auction_tokens()))
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+    //presale sol backs the presale tokens in circulation, so it is redeemed the same way
+    let (curve_sol_amount, presale_sol_amount) = ctx
+        .accounts
+        .bonding_curve
+        .redeem_expired(token_amount, circulating_supply)?;
+    let sol_amount = curve_sol_amount + presale_sol_amount;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        token_amount,
+    )?;
+
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= sol_amount;
+    **ctx.accounts.user.try_borrow_mut_lamports()? += sol_amount;
+
+    let redeem_event = RedeemExpiredEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.user.key().to_string(),
+        token_amount,
+        sol_amount,
+        real_sol_reserves: ctx.accounts.bonding_curve.real_sol_reserves,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&redeem_event).unwrap();
+
+    msg!("redeemExpired:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredCurve<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        mut,
+        address = bonding_curve.mint.key()
+    )]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    token_program: Program<'info, Token>,
+}
+
+//once every holder has redeemed, or the redeem window is over, the creator gets the
+//leftover sol and the vault rent. unclaimed auction tokens and refunds stay with the curve
+//for their participants, the bonding curve stays open to keep its fee ledger
+pub fn close_expired_curve(ctx: Context<CloseExpiredCurve>) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(
+        bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::BondingCurveNotExpired
+    );
+    require!(!bonding_curve.expired_closed, CurveLaunchpadError::ExpiredCurveClosed);
+    //escrowed auction commitments are never swept
+    require!(
+        bonding_curve.auction.commit_end_time == 0 || bonding_curve.auction.settled,
+        CurveLaunchpadError::AuctionNotSettled
+    );
+    let unclaimed_tokens = bonding_curve.unclaimed_auction_tokens();
+    let circulating_supply = ctx
+        .accounts
+        .mint
+        .supply
+        .checked_sub(ctx.accounts.bonding_curve_token_account.amount)
+        .and_then(|supply| supply.checked_add(unclaimed_tokens))
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+    require!(
+        circulating_supply == 0 || curr_time >= bonding_curve.expires_at + EXPIRED_REDEEM_WINDOW_SECONDS,
+        CurveLaunchpadError::RedeemWindowOpen
+    );
+
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    let burned_token_amount = ctx
+        .accounts
+        .bonding_curve_token_account
+        .amount
+        .checked_sub(unclaimed_tokens)
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        burned_token_amount,
+    )?;
+
+    if unclaimed_tokens == 0 {
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ))?;
+    }
+
+    let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(bonding_curve_info.data_len());
+    let sol_amount = bonding_curve_info
+        .lamports()
+        .saturating_sub(rent_exempt + ctx.accounts.bonding_curve.unclaimed_auction_refund());
+
+    **bonding_curve_info.try_borrow_mut_lamports()? -= sol_amount;
+    **ctx.accounts.creator.try_borrow_mut_lamports()? += sol_amount;
+    ctx.accounts.bonding_curve.real_sol_reserves = 0;
+    ctx.accounts.bonding_curve.presale.sol_raised = 0;
+    ctx.accounts.bonding_curve.expired_closed = true;
+
+    let close_event = CloseExpiredCurveEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        sol_amount,
+        burned_token_amount,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&close_event).unwrap();
+
+    msg!("closeExpiredCurve:{}", serialized);
+
+    Ok(())
+}
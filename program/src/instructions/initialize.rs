@@ -1,4 +1,4 @@
-use crate::{state::{FeeAccount, Global}, CurveLaunchpadError, DEFAULT_TOKEN_SUPPLY};
+use crate::{state::{FeeAccount, Global}, CurveLaunchpadError, DEFAULT_TOKEN_SUPPLY, DEFAULT_VIRTUAL_SOL_RESERVES};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -8,6 +8,8 @@ pub struct Initialize<'info> {
 
     #[account(
         init,
+        space = 8 + Global::INIT_SPACE,
+        seeds = [Global::SEED_PREFIX],
         bump,
         payer = authority,
     )]
@@ -37,12 +39,17 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     global.initialized = true;
     global.initial_token_supply = DEFAULT_TOKEN_SUPPLY;
     global.initial_virtual_token_reserves = 1_075_000_000_000_000;
+    global.initial_virtual_sol_reserves = DEFAULT_VIRTUAL_SOL_RESERVES;
+    global.initial_real_token_reserves = 793_100_000_000_000;
     global.fee_basis_points = 50;
 
     global.creator_fee_basis_points = 35;
     global.protocol_token_alloc_points = 50;
     global.protocol_token_alloc_recipient = *ctx.accounts.authority.to_account_info().key;
     global.invite_fee_basis_points = 15;
+    global.invite_level_basis_points = [15, 0, 0];
+    global.max_creator_fee_basis_points = 1000;
+    global.set_default_mint_vanity();
 
     msg!("Initialized global state");
 
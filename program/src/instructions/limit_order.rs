@@ -0,0 +1,719 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    amm, calculate_fee, settle_trade_fee,
+    state::{BondingCurve, FeeAccount, Global, LimitOrder, OrderBook, UserInviteStats},
+    CancelLimitOrderEvent, CompleteEvent, CurveLaunchpadError, EvictLimitOrderEvent, FillLimitOrderEvent,
+    PlaceLimitOrderEvent, TradeFees, MAX_INVITE_LEVELS, MAX_LIMIT_ORDERS, MAX_LIMIT_ORDERS_PER_OWNER,
+    MIN_LIMIT_ORDER_SOL, MIN_LIMIT_ORDER_TOKENS, ORDER_FILL_BOUNTY_BASIS_POINTS,
+};
+
+//number of remaining accounts supplied for every filled order: owner, owner_token_account,
+//owner_invite_account and the owner's parent, grandparent and great grandparent invite accounts.
+//the program id stands in for a level the owner has no referrer for
+pub const ACCOUNTS_PER_ORDER: usize = 3 + MAX_INVITE_LEVELS;
+
+#[derive(Accounts)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + OrderBook::INIT_SPACE,
+        seeds = [OrderBook::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        token::mint = mint,
+        token::authority = order_book,
+        seeds = [OrderBook::VAULT_SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book_vault: Box<Account<'info, TokenAccount>>,
+
+    //only debited for sell orders
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    user_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    //created up front so the crank never pays for it
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
+        bump
+    )]
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn place_limit_order(ctx: Context<PlaceLimitOrder>, is_buy: bool, amount: u64, limit_price: u64) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+
+    check_order_placeable(&ctx.accounts.bonding_curve, curr_time)?;
+    //small orders could never fill and would only hold a slot
+    let min_amount = ctx.accounts.order_book.min_order_amount(if is_buy {
+        MIN_LIMIT_ORDER_SOL
+    } else {
+        MIN_LIMIT_ORDER_TOKENS
+    });
+    require!(amount >= min_amount && limit_price > 0, CurveLaunchpadError::InvalidLimitOrder);
+    require!(
+        ctx.accounts.order_book.count_for(ctx.accounts.user.key) < MAX_LIMIT_ORDERS_PER_OWNER,
+        CurveLaunchpadError::TooManyLimitOrders
+    );
+
+    if is_buy {
+        let transfer_instruction = system_instruction::transfer(
+            ctx.accounts.user.key,
+            ctx.accounts.order_book.to_account_info().key,
+            amount,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &transfer_instruction,
+            &[
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.order_book.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[],
+        )?;
+    } else {
+        let user_token_account = ctx
+            .accounts
+            .user_token_account
+            .as_ref()
+            .ok_or(CurveLaunchpadError::InvalidLimitOrder)?;
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: user_token_account.to_account_info(),
+                    to: ctx.accounts.order_book_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let order_book = &mut ctx.accounts.order_book;
+    order_book.mint = ctx.accounts.mint.key();
+    let order_id = order_book
+        .insert(LimitOrder {
+            id: 0,
+            owner: ctx.accounts.user.key(),
+            is_buy,
+            amount,
+            limit_price,
+            create_time: curr_time,
+        })
+        .ok_or(CurveLaunchpadError::OrderBookFull)?;
+
+    let place_event = PlaceLimitOrderEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.user.key().to_string(),
+        order_id,
+        is_buy,
+        amount,
+        limit_price,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&place_event).unwrap();
+
+    msg!("placeLimitOrder:{}", serialized);
+
+    Ok(())
+}
+
+//orders rest only on a curve open to public trading, the fills would skip them otherwise
+fn check_order_placeable(bonding_curve: &BondingCurve, now: i64) -> Result<()> {
+    require!(!bonding_curve.is_complete(), CurveLaunchpadError::BondingCurveComplete);
+    require!(!bonding_curve.is_expired(now), CurveLaunchpadError::BondingCurveExpired);
+    require!(!bonding_curve.is_presale_active(now), CurveLaunchpadError::TradingNotStarted);
+    bonding_curve.check_trading_open(now, false)
+}
+
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(address = order_book.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [OrderBook::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = order_book,
+        seeds = [OrderBook::VAULT_SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn cancel_limit_order(ctx: Context<CancelLimitOrder>, order_id: u64) -> Result<()> {
+    let index = ctx
+        .accounts
+        .order_book
+        .find(order_id)
+        .ok_or(CurveLaunchpadError::LimitOrderNotFound)?;
+    require_keys_eq!(
+        ctx.accounts.order_book.orders[index].owner,
+        ctx.accounts.user.key(),
+        CurveLaunchpadError::LimitOrderNotFound
+    );
+    let order = ctx.accounts.order_book.remove(index);
+
+    refund_order(
+        &order,
+        &ctx.accounts.order_book,
+        &ctx.accounts.order_book_vault,
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.bumps.order_book,
+    )?;
+
+    let cancel_event = CancelLimitOrderEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.user.key().to_string(),
+        order_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&cancel_event).unwrap();
+
+    msg!("cancelLimitOrder:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EvictLimitOrder<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(address = order_book.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [OrderBook::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = order_book,
+        seeds = [OrderBook::VAULT_SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book_vault: Box<Account<'info, TokenAccount>>,
+
+    ///CHECK: checked against the order owner
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+//anyone may free the slot of an order that is past its ttl or can no longer fill, the escrow goes back to the owner
+pub fn evict_limit_order(ctx: Context<EvictLimitOrder>, order_id: u64) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let index = ctx
+        .accounts
+        .order_book
+        .find(order_id)
+        .ok_or(CurveLaunchpadError::LimitOrderNotFound)?;
+    let order = ctx.accounts.order_book.orders[index];
+    require_keys_eq!(order.owner, ctx.accounts.owner.key(), CurveLaunchpadError::InvalidLimitOrder);
+
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    require!(
+        order.is_stale(curr_time) || bonding_curve.is_complete() || bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::LimitOrderNotEvictable
+    );
+    ctx.accounts.order_book.remove(index);
+
+    refund_order(
+        &order,
+        &ctx.accounts.order_book,
+        &ctx.accounts.order_book_vault,
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.bumps.order_book,
+    )?;
+
+    let evict_event = EvictLimitOrderEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: order.owner.to_string(),
+        evictor: ctx.accounts.user.key().to_string(),
+        order_id,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&evict_event).unwrap();
+
+    msg!("evictLimitOrder:{}", serialized);
+
+    Ok(())
+}
+
+//returns the escrow of a removed order, sol for a buy and tokens for a sell
+fn refund_order<'info>(
+    order: &LimitOrder,
+    order_book: &Account<'info, OrderBook>,
+    order_book_vault: &Account<'info, TokenAccount>,
+    owner: AccountInfo<'info>,
+    owner_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    order_book_bump: u8,
+) -> Result<()> {
+    if order.is_buy {
+        **order_book.to_account_info().try_borrow_mut_lamports()? -= order.amount;
+        **owner.try_borrow_mut_lamports()? += order.amount;
+        return Ok(());
+    }
+
+    let signer: [&[&[u8]]; 1] = [&[OrderBook::SEED_PREFIX, order_book.mint.as_ref(), &[order_book_bump]]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program,
+            Transfer {
+                from: order_book_vault.to_account_info(),
+                to: owner_token_account,
+                authority: order_book.to_account_info(),
+            },
+            &signer,
+        ),
+        order.amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct FillOrders<'info> {
+    #[account(mut)]
+    cranker: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OrderBook::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book: Box<Account<'info, OrderBook>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = order_book,
+        seeds = [OrderBook::VAULT_SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    order_book_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = cranker,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+//permissionless crank. orders whose limit would be crossed by the fill are skipped, so a stale
+//order list never fails the whole transaction. fills pay the same fees as buy and sell, at the
+//owner's volume tier and along the owner's referral chain, and the cranker earns a bounty on top
+pub fn fill_orders<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FillOrders<'info>>,
+    order_ids: Vec<u64>,
+) -> Result<()> {
+    require!(
+        !order_ids.is_empty() && order_ids.len() <= MAX_LIMIT_ORDERS,
+        CurveLaunchpadError::InvalidLimitOrder
+    );
+    require!(
+        ctx.remaining_accounts.len() == order_ids.len() * ACCOUNTS_PER_ORDER,
+        CurveLaunchpadError::InvalidLimitOrder
+    );
+
+    let curr_time = Clock::get()?.unix_timestamp;
+    let program_id = ctx.program_id;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    require!(!bonding_curve.is_complete(), CurveLaunchpadError::BondingCurveComplete);
+    require!(!bonding_curve.is_expired(curr_time), CurveLaunchpadError::BondingCurveExpired);
+    bonding_curve.check_trading_open(curr_time, false)?;
+
+    let mut amm = amm::amm::AMM::new(
+        bonding_curve.virtual_sol_reserves as u128,
+        bonding_curve.virtual_token_reserves as u128,
+        bonding_curve.real_sol_reserves as u128,
+        bonding_curve.real_token_reserves as u128,
+        ctx.accounts.global.initial_virtual_token_reserves as u128,
+    );
+
+    let curve_signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+    let book_signer: [&[&[u8]]; 1] = [&[
+        OrderBook::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.order_book],
+    ]];
+
+    for (i, order_id) in order_ids.iter().enumerate() {
+        let index = match ctx.accounts.order_book.find(*order_id) {
+            Some(index) => index,
+            None => continue,
+        };
+        let order = ctx.accounts.order_book.orders[index];
+
+        let accounts = &ctx.remaining_accounts[i * ACCOUNTS_PER_ORDER..(i + 1) * ACCOUNTS_PER_ORDER];
+        let owner = &accounts[0];
+        require_keys_eq!(owner.key(), order.owner, CurveLaunchpadError::InvalidLimitOrder);
+        require_keys_eq!(
+            accounts[1].key(),
+            get_associated_token_address(&order.owner, &ctx.accounts.mint.key()),
+            CurveLaunchpadError::InvalidLimitOrder
+        );
+        //a buy can't deliver to a missing or closed owner token account, the order waits for it or its eviction
+        if order.is_buy && Account::<TokenAccount>::try_from(&accounts[1]).is_err() {
+            continue;
+        }
+
+        let (owner_invite_key, _) = Pubkey::find_program_address(
+            &[UserInviteStats::SEED_PREFIX, order.owner.as_ref()],
+            program_id,
+        );
+        require_keys_eq!(accounts[2].key(), owner_invite_key, CurveLaunchpadError::InviteAccountError);
+        let mut owner_invite_account: Account<'info, UserInviteStats> = Account::try_from(&accounts[2])?;
+        //validated against the owner's chain in distribute_invite_fee
+        let mut parent_invite_account = optional_invite_account(&accounts[3], program_id)?;
+        let mut grandparent_invite_account = optional_invite_account(&accounts[4], program_id)?;
+        let mut great_grandparent_invite_account = optional_invite_account(&accounts[5], program_id)?;
+
+        let fees = TradeFees::new(
+            &ctx.accounts.global,
+            &ctx.accounts.bonding_curve,
+            &owner_invite_account,
+            order.is_buy,
+            curr_time,
+        );
+
+        //simulate on a copy so a skipped order leaves the curve untouched
+        let mut next_amm = amm::amm::AMM::new(
+            amm.virtual_sol_reserves,
+            amm.virtual_token_reserves,
+            amm.real_sol_reserves,
+            amm.real_token_reserves,
+            amm.initial_virtual_token_reserves,
+        );
+
+        let (token_amount, sol_amount) = if order.is_buy {
+            let sol_for_curve = order.amount as u128 * 10000
+                / (10000 + fees.total_basis_points() + ORDER_FILL_BOUNTY_BASIS_POINTS) as u128;
+            let buy_result = match next_amm
+                .get_tokens_for_sol(sol_for_curve)
+                .filter(|tokens| *tokens > 0)
+                .and_then(|tokens| next_amm.apply_buy(tokens))
+            {
+                Some(buy_result) => buy_result,
+                None => continue,
+            };
+            let price_after = next_amm
+                .get_marginal_price()
+                .ok_or(CurveLaunchpadError::MathOverflow)?;
+            if price_after > order.limit_price as u128 {
+                continue;
+            }
+            (buy_result.token_amount, buy_result.sol_amount)
+        } else {
+            let sell_result = match next_amm.apply_sell(order.amount as u128) {
+                Some(sell_result) => sell_result,
+                None => continue,
+            };
+            let price_after = next_amm
+                .get_marginal_price()
+                .ok_or(CurveLaunchpadError::MathOverflow)?;
+            if price_after < order.limit_price as u128 {
+                continue;
+            }
+            (sell_result.token_amount, sell_result.sol_amount)
+        };
+        let fee = fees.fee(sol_amount);
+        let bounty = calculate_fee(sol_amount, ORDER_FILL_BOUNTY_BASIS_POINTS);
+        //a buy gets back what its escrow doesn't spend and a sell the sol left after the fees. an order
+        //that can't cover its fees is skipped like one past its limit
+        let owner_proceeds = if order.is_buy {
+            order.amount.checked_sub(sol_amount + fee + bounty)
+        } else {
+            sol_amount.checked_sub(fee + bounty)
+        };
+        let owner_proceeds = match owner_proceeds {
+            Some(owner_proceeds) => owner_proceeds,
+            None => continue,
+        };
+        amm = next_amm;
+        ctx.accounts.order_book.remove(index);
+
+        let book_info = ctx.accounts.order_book.to_account_info();
+        let curve_info = ctx.accounts.bonding_curve.to_account_info();
+        if order.is_buy {
+            //escrowed sol pays the curve, the fees and the bounty, the rest is refunded
+            **book_info.try_borrow_mut_lamports()? -= order.amount;
+            **curve_info.try_borrow_mut_lamports()? += sol_amount;
+            **owner.try_borrow_mut_lamports()? += owner_proceeds;
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                        to: accounts[1].clone(),
+                        authority: curve_info.clone(),
+                    },
+                    &curve_signer,
+                ),
+                token_amount,
+            )?;
+        } else {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.order_book_vault.to_account_info(),
+                        to: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                        authority: book_info.clone(),
+                    },
+                    &book_signer,
+                ),
+                token_amount,
+            )?;
+
+            **curve_info.try_borrow_mut_lamports()? -= sol_amount;
+            **owner.try_borrow_mut_lamports()? += owner_proceeds;
+        }
+
+        **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? += fee;
+        **ctx.accounts.cranker.try_borrow_mut_lamports()? += bounty;
+
+        settle_trade_fee(
+            &ctx.accounts.global,
+            &fees,
+            sol_amount,
+            &mut ctx.accounts.bonding_curve,
+            &mut owner_invite_account,
+            [
+                parent_invite_account.as_mut(),
+                grandparent_invite_account.as_mut(),
+                great_grandparent_invite_account.as_mut(),
+            ],
+            &mut ctx.accounts.fee_recipient_invite_account,
+            &mut ctx.accounts.fee_account,
+            program_id,
+        )?;
+
+        //written back before a later order of the same owner or chain loads them again
+        owner_invite_account.exit(program_id)?;
+        for ancestor in [parent_invite_account, grandparent_invite_account, great_grandparent_invite_account]
+            .iter()
+            .flatten()
+        {
+            ancestor.exit(program_id)?;
+        }
+
+        let fill_event = FillLimitOrderEvent {
+            mint: ctx.accounts.mint.key().to_string(),
+            user: order.owner.to_string(),
+            cranker: ctx.accounts.cranker.key().to_string(),
+            order_id: order.id,
+            is_buy: order.is_buy,
+            token_amount,
+            sol_amount,
+            fee,
+            bounty,
+            owner_proceeds,
+            timestamp: curr_time,
+        };
+        let serialized = serde_json::to_string(&fill_event).unwrap();
+        msg!("fillLimitOrder:{}", serialized);
+    }
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_token_reserves = amm.real_token_reserves as u64;
+    bonding_curve.real_sol_reserves = amm.real_sol_reserves as u64;
+    bonding_curve.virtual_token_reserves = amm.virtual_token_reserves as u64;
+    bonding_curve.virtual_sol_reserves = amm.virtual_sol_reserves as u64;
+    bonding_curve.update_time = curr_time as u64;
+
+    if bonding_curve.real_token_reserves == 0 {
+        bonding_curve.complete = true;
+        let complete_event = CompleteEvent {
+            user: ctx.accounts.cranker.key().to_string(),
+            mint: ctx.accounts.mint.key().to_string(),
+            bonding_curve: ctx.accounts.bonding_curve.key().to_string(),
+            timestamp: curr_time,
+        };
+        let serialized = serde_json::to_string(&complete_event).unwrap();
+        msg!("completelog:{}", serialized);
+    }
+
+    Ok(())
+}
+
+//the program id marks a referral level that is not supplied, as anchor does for optional accounts
+fn optional_invite_account<'info>(
+    info: &'info AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<Option<Account<'info, UserInviteStats>>> {
+    if info.key == program_id {
+        return Ok(None);
+    }
+    Ok(Some(Account::try_from(info)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LaunchSchedule;
+
+    #[test]
+    fn test_check_order_placeable() {
+        let mut bonding_curve = BondingCurve {
+            real_token_reserves: 100,
+            launch_schedule: LaunchSchedule {
+                trading_start_time: 100,
+                allowlist_start_time: 50,
+            },
+            ..Default::default()
+        };
+        assert!(check_order_placeable(&bonding_curve, 100).is_ok());
+        //allowlisted users trade before the public start but their orders wait for it
+        assert_eq!(
+            check_order_placeable(&bonding_curve, 60).unwrap_err(),
+            CurveLaunchpadError::NotAllowlisted.into()
+        );
+
+        bonding_curve.presale.merkle_root = [1; 32];
+        bonding_curve.presale.end_time = 150;
+        assert_eq!(
+            check_order_placeable(&bonding_curve, 120).unwrap_err(),
+            CurveLaunchpadError::TradingNotStarted.into()
+        );
+        assert!(check_order_placeable(&bonding_curve, 150).is_ok());
+
+        bonding_curve.expires_at = 200;
+        assert_eq!(
+            check_order_placeable(&bonding_curve, 200).unwrap_err(),
+            CurveLaunchpadError::BondingCurveExpired.into()
+        );
+
+        bonding_curve.real_token_reserves = 0;
+        assert_eq!(
+            check_order_placeable(&bonding_curve, 150).unwrap_err(),
+            CurveLaunchpadError::BondingCurveComplete.into()
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::token::Mint;
+
+use crate::{
+    state::{AuctionState, BondingCurve, FeeSchedule, FeeTier, Global, LaunchSchedule, PresaleConfig, UserInviteStats},
+    CurveLaunchpadError, DEFAULT_TOKEN_SUPPLY, DEFAULT_VIRTUAL_SOL_RESERVES, MAX_FEE_TIERS, MAX_INVITE_LEVELS,
+    VOLUME_WINDOW_DAYS,
+};
+
+#[derive(Accounts)]
+pub struct MigrateGlobal<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    ///CHECK: legacy layout, loaded after the realloc and checked against the authority
+    #[account(
+        mut,
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+//grows the legacy global to the current layout. the appended fields read as zero after the realloc,
+//so each gets an explicit default
+pub fn migrate_global(ctx: Context<MigrateGlobal>) -> Result<()> {
+    let info = ctx.accounts.global.to_account_info();
+    grow_account(&info, 8 + Global::INIT_SPACE, &ctx.accounts.authority, &ctx.accounts.system_program)?;
+
+    let mut global = Global::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require_keys_eq!(global.authority, ctx.accounts.authority.key(), CurveLaunchpadError::InvalidAuthority);
+
+    //keeps the legacy creator fee a valid default schedule
+    global.max_creator_fee_basis_points = global.creator_fee_basis_points.max(1000);
+    global.initial_virtual_sol_reserves = DEFAULT_VIRTUAL_SOL_RESERVES;
+    global.initial_token_supply = DEFAULT_TOKEN_SUPPLY;
+    //no volume discount until tiers are configured
+    global.fee_tiers = [FeeTier::default(); MAX_FEE_TIERS];
+    //legacy invite fees were paid to the direct parent only
+    global.invite_level_basis_points = [0; MAX_INVITE_LEVELS];
+    global.invite_level_basis_points[0] = global.invite_fee_basis_points;
+    global.invite_requires_trade = false;
+    //a zero length would disable the vanity rule, keep requiring the "meme" suffix
+    global.set_default_mint_vanity();
+
+    //the new max creator fee must still leave the protocol and invite fees room
+    require!(global.fee_basis_points_in_bounds(), CurveLaunchpadError::InvalidFeeParams);
+
+    global.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated global state");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateBondingCurve<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    mint: Account<'info, Mint>,
+
+    ///CHECK: legacy layout, loaded after the realloc
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+//permissionless, the curve keeps trading as before at the global creator fee. legacy creator fees
+//were paid into the creator's invite account, so nothing is claimable on the curve yet. no creator
+//transfer is pending, the curve never expires and it has no allowlist, presale or auction phase
+pub fn migrate_bonding_curve(ctx: Context<MigrateBondingCurve>) -> Result<()> {
+    let info = ctx.accounts.bonding_curve.to_account_info();
+    grow_account(&info, 8 + BondingCurve::INIT_SPACE, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+
+    let mut bonding_curve = BondingCurve::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    let creator_fee_schedule = FeeSchedule::flat(ctx.accounts.global.creator_fee_basis_points);
+    bonding_curve.buy_fee_schedule = creator_fee_schedule;
+    bonding_curve.sell_fee_schedule = creator_fee_schedule;
+    bonding_curve.creator_fee_recipient = bonding_curve.creator;
+    bonding_curve.creator_fee_claimable = 0;
+    bonding_curve.creator_fee_claimed = 0;
+    bonding_curve.creator_fee_renounced = false;
+    bonding_curve.expires_at = 0;
+    bonding_curve.expired_closed = false;
+    bonding_curve.launch_schedule = LaunchSchedule::default();
+    bonding_curve.presale = PresaleConfig::default();
+    bonding_curve.auction = AuctionState::default();
+    bonding_curve.pending_creator = Pubkey::default();
+
+    bonding_curve.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated bonding curve {}", ctx.accounts.mint.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct MigrateUserInviteStats<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    ///CHECK: legacy layout, loaded after the realloc
+    #[account(
+        mut,
+        seeds = [UserInviteStats::SEED_PREFIX, owner.as_ref()],
+        bump,
+    )]
+    user_invite_account: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+//permissionless. the volume window of a legacy account starts empty and its invite earnings all
+//came from direct children. the account predates the trade requirement so its referrer keeps earning,
+//a zero bind time leaves the binding final and no one claims on the owner's behalf
+pub fn migrate_user_invite_stats(ctx: Context<MigrateUserInviteStats>, owner: Pubkey) -> Result<()> {
+    let info = ctx.accounts.user_invite_account.to_account_info();
+    grow_account(&info, 8 + UserInviteStats::INIT_SPACE, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+
+    let mut user_invite_account = UserInviteStats::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    user_invite_account.profit_from_level = [0; MAX_INVITE_LEVELS];
+    user_invite_account.profit_from_level[0] = user_invite_account.profit_from_child;
+    user_invite_account.volume_day = 0;
+    user_invite_account.volume_buckets = [0; VOLUME_WINDOW_DAYS];
+    user_invite_account.has_traded = true;
+    user_invite_account.bind_time = 0;
+    user_invite_account.rebound = false;
+    user_invite_account.claim_delegate = Pubkey::default();
+
+    user_invite_account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated invite account of {}", owner);
+
+    Ok(())
+}
+
+//reallocs a legacy account to the current layout, topping up its rent. the appended bytes are zeroed
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    require!(account.data_len() < space, CurveLaunchpadError::AlreadyMigrated);
+
+    let lamports = rent_top_up(&Rent::get()?, account.data_len(), space);
+    if lamports > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports),
+            &[
+                payer.to_account_info(),
+                account.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(())
+}
+
+//rent of the appended bytes only, lamports the account holds on top of its rent are not counted
+fn rent_top_up(rent: &Rent, data_len: usize, space: usize) -> u64 {
+    rent.minimum_balance(space).saturating_sub(rent.minimum_balance(data_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rent_top_up() {
+        let rent = Rent::default();
+
+        assert_eq!(rent_top_up(&rent, 100, 100), 0);
+        assert_eq!(
+            rent_top_up(&rent, 100, 164),
+            rent.minimum_balance(164) - rent.minimum_balance(100)
+        );
+        //a curve holding sol reserves still pays for the appended bytes
+        assert!(rent_top_up(&rent, 100, 164) > 0);
+    }
+}
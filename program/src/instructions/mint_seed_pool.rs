@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    state::{BondingCurve, Global, MintSeedPool},
+    AddMintSeedsEvent, CurveLaunchpadError, RemoveMintSeedEvent, ReserveMintSeedEvent, DEFAULT_DECIMALS,
+};
+
+#[derive(Accounts)]
+pub struct AddMintSeeds<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintSeedPool::INIT_SPACE,
+        seeds = [MintSeedPool::SEED_PREFIX],
+        bump,
+    )]
+    mint_seed_pool: Box<Account<'info, MintSeedPool>>,
+
+    system_program: Program<'info, System>,
+}
+
+//seeds come from the grinder binary, their mints must satisfy the current vanity rule
+pub fn add_mint_seeds(ctx: Context<AddMintSeeds>, seeds: Vec<u64>) -> Result<()> {
+    let mint_seed_pool = &mut ctx.accounts.mint_seed_pool;
+    for seed in seeds.iter() {
+        require!(
+            ctx.accounts.global.mint_matches_vanity(&MintSeedPool::mint_address(*seed)),
+            CurveLaunchpadError::InvalidMintAddress
+        );
+        require!(mint_seed_pool.push(*seed), CurveLaunchpadError::MintSeedPoolFull);
+    }
+
+    let add_event = AddMintSeedsEvent {
+        count: seeds.len() as u64,
+        pool_size: mint_seed_pool.len as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&add_event).unwrap();
+
+    msg!("addMintSeeds:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveMintSeed<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(
+        mut,
+        seeds = [MintSeedPool::SEED_PREFIX],
+        bump,
+    )]
+    mint_seed_pool: Box<Account<'info, MintSeedPool>>,
+}
+
+//drops a seed that can no longer be reserved, e.g. after the vanity rule changed
+pub fn remove_mint_seed(ctx: Context<RemoveMintSeed>, index: u16) -> Result<()> {
+    require!(
+        index < ctx.accounts.mint_seed_pool.len,
+        CurveLaunchpadError::InvalidMintSeedIndex
+    );
+
+    let seed = ctx.accounts.mint_seed_pool.swap_remove(index);
+
+    let remove_event = RemoveMintSeedEvent {
+        seed,
+        pool_size: ctx.accounts.mint_seed_pool.len as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&remove_event).unwrap();
+
+    msg!("removeMintSeed:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u16)]
+pub struct ReserveMintSeed<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MintSeedPool::SEED_PREFIX],
+        bump,
+    )]
+    mint_seed_pool: Box<Account<'info, MintSeedPool>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [MintSeedPool::MINT_SEED_PREFIX.as_ref(), &mint_seed_pool.seed_at(index).to_le_bytes()],
+        bump,
+        mint::decimals = DEFAULT_DECIMALS as u8,
+        mint::authority = bonding_curve,
+    )]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.to_account_info().key.as_ref()],
+        bump,
+        space = 8 + BondingCurve::INIT_SPACE,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+//like init_create_account, but the seed comes from the pool and the curve is reserved for the creator
+pub fn reserve_mint_seed(ctx: Context<ReserveMintSeed>, index: u16) -> Result<()> {
+    require!(
+        index < ctx.accounts.mint_seed_pool.len,
+        CurveLaunchpadError::InvalidMintSeedIndex
+    );
+
+    let seed = ctx.accounts.mint_seed_pool.swap_remove(index);
+    ctx.accounts.bonding_curve.creator = ctx.accounts.creator.key();
+
+    let reserve_event = ReserveMintSeedEvent {
+        creator: ctx.accounts.creator.key().to_string(),
+        mint: ctx.accounts.mint.key().to_string(),
+        seed,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&reserve_event).unwrap();
+
+    msg!("reserveMintSeed:{}", serialized);
+
+    Ok(())
+}
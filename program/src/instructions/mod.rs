@@ -9,6 +9,22 @@ pub mod events;
 pub mod util;
 pub mod withdraw;
 pub mod user_invite;
+pub mod trade_fees;
+pub mod batch_trade;
+pub mod vesting;
+pub mod creator_fees;
+pub mod transfer_creator;
+pub mod token_profile;
+pub mod token_metadata;
+pub mod mint_seed_pool;
+pub mod expiry;
+pub mod allowlist;
+pub mod presale;
+pub mod auction;
+pub mod limit_order;
+pub mod dca;
+pub mod buyback;
+pub mod migrate;
 
 pub use initialize::*;
 pub use errors::*;
@@ -20,4 +36,20 @@ pub use constants::*;
 pub use events::*;
 pub use util::*;
 pub use withdraw::*;
-pub use user_invite::*;
\ No newline at end of file
+pub use user_invite::*;
+pub use trade_fees::*;
+pub use batch_trade::*;
+pub use vesting::*;
+pub use creator_fees::*;
+pub use transfer_creator::*;
+pub use token_profile::*;
+pub use token_metadata::*;
+pub use mint_seed_pool::*;
+pub use expiry::*;
+pub use allowlist::*;
+pub use presale::*;
+pub use auction::*;
+pub use limit_order::*;
+pub use dca::*;
+pub use buyback::*;
+pub use migrate::*;
\ No newline at end of file
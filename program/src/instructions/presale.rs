@@ -0,0 +1,217 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::hashv, system_instruction},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    amm::amm::PRICE_PRECISION,
+    calculate_fee,
+    state::{BondingCurve, FeeAccount, Global, PresaleClaim, UserInviteStats},
+    verify_merkle_proof, CurveLaunchpadError, PresaleBuyEvent,
+};
+
+#[derive(Accounts)]
+pub struct PresaleBuy<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds=[BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PresaleClaim::INIT_SPACE,
+        seeds = [PresaleClaim::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    presale_claim: Box<Account<'info, PresaleClaim>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds=[FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+//leaves are hash(wallet || max_tokens as little endian u64)
+pub fn presale_leaf(user: &Pubkey, max_tokens: u64) -> [u8; 32] {
+    hashv(&[user.as_ref(), &max_tokens.to_le_bytes()]).to_bytes()
+}
+
+//presale buys only pay the protocol fee and leave the virtual reserves, and so the public opening price, untouched.
+//the sol is kept in presale.sol_raised since it was not paid along the curve
+pub fn presale_buy(
+    ctx: Context<PresaleBuy>,
+    token_amount: u64,
+    max_sol_cost: u64,
+    max_tokens: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(
+        bonding_curve.is_presale_active(curr_time),
+        CurveLaunchpadError::PresaleNotActive
+    );
+    require!(!bonding_curve.complete, CurveLaunchpadError::BondingCurveComplete);
+    require!(
+        !bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::BondingCurveExpired
+    );
+
+    let user = ctx.accounts.user.key();
+    require!(
+        verify_merkle_proof(
+            &proof,
+            &bonding_curve.presale.merkle_root,
+            presale_leaf(&user, max_tokens),
+            |a, b| hashv(&[a, b]).to_bytes(),
+        ),
+        CurveLaunchpadError::InvalidPresaleProof
+    );
+
+    require!(token_amount > 0, CurveLaunchpadError::InsufficientTokens);
+    require!(
+        ctx.accounts
+            .presale_claim
+            .claimed_tokens
+            .checked_add(token_amount)
+            .is_some_and(|claimed_tokens| claimed_tokens <= max_tokens)
+            && bonding_curve
+                .presale
+                .sold_tokens
+                .checked_add(token_amount)
+                .is_some_and(|sold_tokens| sold_tokens <= bonding_curve.presale.allocation),
+        CurveLaunchpadError::PresaleAllocationExceeded
+    );
+    //the presale never drains the curve, completion is left to trading
+    require!(
+        bonding_curve.real_token_reserves > token_amount,
+        CurveLaunchpadError::InsufficientTokens
+    );
+
+    let sol_amount = (token_amount as u128 * bonding_curve.presale.price as u128).div_ceil(PRICE_PRECISION);
+    let sol_amount = u64::try_from(sol_amount).map_err(|_| CurveLaunchpadError::MathOverflow)?;
+    let fee = calculate_fee(sol_amount, ctx.accounts.global.fee_basis_points);
+    require!(
+        sol_amount.checked_add(fee).is_some_and(|cost| cost <= max_sol_cost),
+        CurveLaunchpadError::MaxSOLCostExceeded
+    );
+
+    //transfer SOL to bonding curve and fee account
+    let from_account = &ctx.accounts.user;
+    for (to_account, amount) in [
+        (ctx.accounts.bonding_curve.to_account_info(), sol_amount),
+        (ctx.accounts.fee_account.to_account_info(), fee),
+    ] {
+        let transfer_instruction = system_instruction::transfer(from_account.key, to_account.key, amount);
+        anchor_lang::solana_program::program::invoke_signed(
+            &transfer_instruction,
+            &[
+                from_account.to_account_info(),
+                to_account,
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[],
+        )?;
+    }
+
+    ctx.accounts.fee_account.received += fee;
+    let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
+    require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+    ctx.accounts.fee_recipient_invite_account.profit_claimable += fee;
+
+    //transfer SPL
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        token_amount,
+    )?;
+
+    let presale_claim = &mut ctx.accounts.presale_claim;
+    presale_claim.mint = ctx.accounts.mint.key();
+    presale_claim.user = user;
+    presale_claim.claimed_tokens += token_amount;
+
+    ctx.accounts.bonding_curve.record_presale_buy(token_amount, sol_amount)?;
+
+    let presale_event = PresaleBuyEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: user.to_string(),
+        token_amount,
+        sol_amount,
+        fee,
+        claimed_tokens: presale_claim.claimed_tokens,
+        max_tokens,
+        timestamp: curr_time,
+    };
+
+    let serialized = serde_json::to_string(&presale_event).unwrap();
+
+    msg!("presaleBuy:{}", serialized);
+
+    Ok(())
+}
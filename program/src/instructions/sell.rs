@@ -1,5 +1,5 @@
 use crate::{
-    amm, calculate_fee, state::{BondingCurve, FeeAccount, Global, UserInviteStats}, CurveLaunchpadError, TradeEvent
+    amm, is_within_deadline, is_within_price_limit, settle_trade_fee, state::{AllowlistEntry, BondingCurve, FeeAccount, Global, UserInviteStats}, CurveLaunchpadError, TradeEvent, TradeFees
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
@@ -48,33 +48,42 @@ pub struct Sell<'info> {
 
 
 
+    //only needed during the allowlist phase
     #[account(
-        mut,
-        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
-        bump
+        seeds = [AllowlistEntry::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump,
     )]
-    user_invite_account: Box<Account<'info, UserInviteStats>>,
+    allowlist_entry: Option<Box<Account<'info, AllowlistEntry>>>,
 
     #[account(
-        mut,
-        seeds=[UserInviteStats::SEED_PREFIX,user_invite_account.parent.key().as_ref()],
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
         bump
     )]
-    parent_invite_account: Box<Account<'info, UserInviteStats>>,
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    //referral chain, only needed when the trader has a referrer.
+    //validated against the chain in distribute_invite_fee
+    #[account(mut)]
+    parent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
+
+    #[account(mut)]
+    great_grandparent_invite_account: Option<Box<Account<'info, UserInviteStats>>>,
 
     #[account(
-        mut,
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
         seeds=[UserInviteStats::SEED_PREFIX,global.fee_recipient.key().as_ref()],
         bump
     )]
     fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
 
-    #[account(
-        mut,
-        seeds=[UserInviteStats::SEED_PREFIX,bonding_curve.creator.key().as_ref()],
-        bump
-    )]
-    creator_fee_recipient_invite_account: Box<Account<'info,UserInviteStats>>,
 
 
     #[account(
@@ -90,14 +99,38 @@ pub struct Sell<'info> {
     token_program: Program<'info, Token>,
 }
 
-pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64 , hash : &str) -> Result<()> {
+pub fn sell(
+    ctx: Context<Sell>,
+    token_amount: u64,
+    min_sol_output: u64,
+    hash: &str,
+    deadline: Option<i64>,
+    min_price: Option<u64>,
+) -> Result<()> {
+    let curr_time = Clock::get()?.unix_timestamp;
+
+    //transaction has not expired
+    require!(
+        is_within_deadline(curr_time, deadline),
+        CurveLaunchpadError::DeadlineExceeded
+    );
+
     //check if bonding curve is complete
     require!(
         !ctx.accounts.bonding_curve.complete,
         CurveLaunchpadError::BondingCurveComplete,
     );
 
-    require!(ctx.accounts.user_invite_account.is_init,CurveLaunchpadError::InviteAccountError);
+    //before the public start only allowlisted users can trade
+    ctx.accounts
+        .bonding_curve
+        .check_trading_open(curr_time, ctx.accounts.allowlist_entry.is_some())?;
+
+    //expired curves are settled through redeem_expired
+    require!(
+        !ctx.accounts.bonding_curve.is_expired(curr_time),
+        CurveLaunchpadError::BondingCurveExpired,
+    );
 
 
     //confirm user has enough tokens
@@ -123,12 +156,29 @@ pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64 , hash :
     );
 
     let sell_result = amm.apply_sell(token_amount as u128).unwrap();
-    let fee = calculate_fee(
-        sell_result.sol_amount,
-        ctx.accounts.global.fee_basis_points + ctx.accounts.global.creator_fee_basis_points + ctx.accounts.global.invite_fee_basis_points,
+
+    //check the marginal price after the sell is not below the min_price
+    let price_after = amm
+        .get_marginal_price()
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+    require!(
+        is_within_price_limit(price_after, min_price, false),
+        CurveLaunchpadError::PriceSlippageExceeded,
+    );
+
+    let fees = TradeFees::new(
+        &ctx.accounts.global,
+        &ctx.accounts.bonding_curve,
+        &ctx.accounts.user_invite_account,
+        false,
+        curr_time,
     );
+    let fee = fees.fee(sell_result.sol_amount);
     //the fee is subtracted from the sol amount to confirm the user minimum sol output is met
-    let sell_amount_minus_fee = sell_result.sol_amount - fee;
+    let sell_amount_minus_fee = sell_result
+        .sol_amount
+        .checked_sub(fee)
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
 
     //confirm min sol output is greater than sol output
     require!(
@@ -169,22 +219,21 @@ pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64 , hash :
 
 
 
-    ctx.accounts.fee_account.received += fee;
-    let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
-    require!(is_ok,CurveLaunchpadError::FeeAccountStatusAbnormal);
-
-    
-    let protocol_fee = calculate_fee(sell_result.sol_amount, ctx.accounts.global.fee_basis_points);
-    let creator_fee = calculate_fee(sell_result.sol_amount, ctx.accounts.global.creator_fee_basis_points);
-    let invite_fee = calculate_fee(sell_result.sol_amount, ctx.accounts.global.invite_fee_basis_points);
-
-
-    ctx.accounts.fee_recipient_invite_account.profit_claimable += protocol_fee;
-    ctx.accounts.creator_fee_recipient_invite_account.profit_claimable += creator_fee;
-    ctx.accounts.parent_invite_account.profit_claimable += invite_fee;
-
-    ctx.accounts.user_invite_account.profit_to_parent += invite_fee;
-    ctx.accounts.parent_invite_account.profit_from_child += invite_fee;
+    settle_trade_fee(
+        &ctx.accounts.global,
+        &fees,
+        sell_result.sol_amount,
+        &mut ctx.accounts.bonding_curve,
+        &mut ctx.accounts.user_invite_account,
+        [
+            ctx.accounts.parent_invite_account.as_deref_mut(),
+            ctx.accounts.grandparent_invite_account.as_deref_mut(),
+            ctx.accounts.great_grandparent_invite_account.as_deref_mut(),
+        ],
+        &mut ctx.accounts.fee_recipient_invite_account,
+        &mut ctx.accounts.fee_account,
+        ctx.program_id,
+    )?;
 
 
 
@@ -200,13 +249,14 @@ pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64 , hash :
         token_amount: sell_result.token_amount,
         is_buy: false,
         user: ctx.accounts.user.to_account_info().key().to_string(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: curr_time,
         virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
         virtual_token_reserves: bonding_curve.virtual_token_reserves,
         real_sol_reserves: bonding_curve.real_sol_reserves,
         real_token_reserves: bonding_curve.real_token_reserves,
 
         hash: hash.to_string(),
+        fee_tier: fees.fee_tier,
     };
 
     let serialized = serde_json::to_string(&trade_event).unwrap();
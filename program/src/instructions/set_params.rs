@@ -1,4 +1,4 @@
-use crate::{state::Global, CurveLaunchpadError};
+use crate::{state::{FeeTier, Global}, CurveLaunchpadError, BASE58_ALPHABET, MAX_FEE_TIERS, MAX_INVITE_LEVELS, MAX_MINT_VANITY_LEN};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -9,6 +9,7 @@ pub struct SetParams<'info> {
         seeds = [Global::SEED_PREFIX],
         bump,
     )]
+    global: Box<Account<'info, Global>>,
 
     #[account(mut)]
     authority: Signer<'info>,
@@ -36,6 +37,7 @@ pub fn set_amm_params(
     Ok(())
 }
 
+//the invite fee is split by set_invite_levels and must match the sum of the levels
 pub fn set_fee_params(
     ctx: Context<SetParams>,
     fee_recipient: Pubkey,
@@ -43,13 +45,23 @@ pub fn set_fee_params(
     creator_fee_basis_points: u64,
     withdraw_authority: Pubkey,
     invite_fee_basis_points: u64,
+    max_creator_fee_basis_points: u64,
 ) -> Result<()> {
     let global = &mut ctx.accounts.global;
     require!(global.initialized, CurveLaunchpadError::NotInitialized);
 
+    require!(
+        invite_fee_basis_points == global.invite_level_basis_points.iter().sum::<u64>(),
+        CurveLaunchpadError::InvalidInviteLevels
+    );
+
     global.fee_recipient = fee_recipient;
+    global.withdraw_authority = withdraw_authority;
     global.fee_basis_points = fee_basis_points;
     global.invite_fee_basis_points = invite_fee_basis_points;
+    global.creator_fee_basis_points = creator_fee_basis_points;
+    global.max_creator_fee_basis_points = max_creator_fee_basis_points;
+    require!(global.fee_basis_points_in_bounds(), CurveLaunchpadError::InvalidFeeParams);
 
     Ok(())
 }
@@ -62,4 +74,87 @@ pub fn set_protocol_fee_address(ctx: Context<SetParams>,protocol_token_alloc_rec
     ctx.accounts.global.fee_recipient = fee_recipient;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+pub fn set_fee_tiers(ctx: Context<SetParams>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    require!(global.initialized, CurveLaunchpadError::NotInitialized);
+    require!(fee_tiers.len() <= MAX_FEE_TIERS, CurveLaunchpadError::InvalidFeeTiers);
+
+    //tiers must be ordered by strictly increasing volume and discount at most the full fee
+    for (i, fee_tier) in fee_tiers.iter().enumerate() {
+        require!(fee_tier.min_volume > 0, CurveLaunchpadError::InvalidFeeTiers);
+        require!(fee_tier.discount_basis_points <= 10000, CurveLaunchpadError::InvalidFeeTiers);
+        if i > 0 {
+            require!(
+                fee_tier.min_volume > fee_tiers[i - 1].min_volume,
+                CurveLaunchpadError::InvalidFeeTiers
+            );
+        }
+    }
+
+    global.fee_tiers = [FeeTier::default(); MAX_FEE_TIERS];
+    global.fee_tiers[..fee_tiers.len()].copy_from_slice(&fee_tiers);
+
+    Ok(())
+}
+
+
+pub fn set_invite_levels(
+    ctx: Context<SetParams>,
+    invite_level_basis_points: Vec<u64>,
+    invite_requires_trade: bool,
+) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    require!(global.initialized, CurveLaunchpadError::NotInitialized);
+    require!(
+        !invite_level_basis_points.is_empty() && invite_level_basis_points.len() <= MAX_INVITE_LEVELS,
+        CurveLaunchpadError::InvalidInviteLevels
+    );
+
+    //a level can only be paid if every level below it is paid
+    for i in 1..invite_level_basis_points.len() {
+        require!(
+            invite_level_basis_points[i] == 0 || invite_level_basis_points[i - 1] > 0,
+            CurveLaunchpadError::InvalidInviteLevels
+        );
+    }
+
+    global.invite_level_basis_points = [0; MAX_INVITE_LEVELS];
+    global.invite_level_basis_points[..invite_level_basis_points.len()].copy_from_slice(&invite_level_basis_points);
+    global.invite_fee_basis_points = invite_level_basis_points.iter().sum();
+    global.invite_requires_trade = invite_requires_trade;
+    require!(global.fee_basis_points_in_bounds(), CurveLaunchpadError::InvalidFeeParams);
+
+    Ok(())
+}
+
+
+//an empty pattern removes the rule
+pub fn set_mint_vanity_rule(
+    ctx: Context<SetParams>,
+    pattern: String,
+    is_prefix: bool,
+    case_sensitive: bool,
+) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+    require!(global.initialized, CurveLaunchpadError::NotInitialized);
+    require!(
+        pattern.len() <= MAX_MINT_VANITY_LEN
+            && pattern.bytes().all(|c| {
+                BASE58_ALPHABET
+                    .iter()
+                    .any(|a| if case_sensitive { *a == c } else { a.eq_ignore_ascii_case(&c) })
+            }),
+        CurveLaunchpadError::InvalidMintVanityRule
+    );
+
+    global.mint_vanity_pattern = [0; MAX_MINT_VANITY_LEN];
+    global.mint_vanity_pattern[..pattern.len()].copy_from_slice(pattern.as_bytes());
+    global.mint_vanity_len = pattern.len() as u8;
+    global.mint_vanity_is_prefix = is_prefix;
+    global.mint_vanity_case_sensitive = case_sensitive;
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{
+        mpl_token_metadata::types::DataV2, update_metadata_accounts_v2, Metadata as Metaplex,
+        UpdateMetadataAccountsV2,
+    },
+    token::Mint,
+};
+
+use crate::{state::BondingCurve, validate_token_metadata, CurveLaunchpadError, FreezeTokenMetadataEvent, UpdateTokenMetadataEvent};
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadata<'info> {
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    ///CHECK: Using seed to validate metadata account
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key.as_ref(),
+            mint.to_account_info().key.as_ref()
+        ],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    metadata: AccountInfo<'info>,
+
+    token_metadata_program: Program<'info, Metaplex>,
+}
+
+pub fn update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.bonding_curve.is_complete(),
+        CurveLaunchpadError::BondingCurveComplete
+    );
+    validate_token_metadata(&name, &symbol, &uri)?;
+
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    let token_data: DataV2 = DataV2 {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        None,
+        Some(token_data),
+        None,
+        None,
+    )?;
+
+    let update_event = UpdateTokenMetadataEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        name,
+        symbol,
+        uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&update_event).unwrap();
+
+    msg!("updateTokenMetadata:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FreezeTokenMetadata<'info> {
+    user: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    ///CHECK: Using seed to validate metadata account
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key.as_ref(),
+            mint.to_account_info().key.as_ref()
+        ],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    metadata: AccountInfo<'info>,
+
+    token_metadata_program: Program<'info, Metaplex>,
+}
+
+//permissionless once the curve has graduated, metadata can never change afterwards
+pub fn freeze_token_metadata(ctx: Context<FreezeTokenMetadata>) -> Result<()> {
+    require!(
+        ctx.accounts.bonding_curve.is_complete(),
+        CurveLaunchpadError::BondingCurveNotComplete
+    );
+
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        None,
+        None,
+        None,
+        Some(false),
+    )?;
+
+    let freeze_event = FreezeTokenMetadataEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        user: ctx.accounts.user.key().to_string(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&freeze_event).unwrap();
+
+    msg!("freezeTokenMetadata:{}", serialized);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    is_valid_uri,
+    state::{BondingCurve, TokenProfile},
+    validate_token_socials, CurveLaunchpadError, UpdateProfileEvent,
+};
+
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [TokenProfile::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    token_profile: Box<Account<'info, TokenProfile>>,
+}
+
+pub fn update_profile(
+    ctx: Context<UpdateProfile>,
+    description: String,
+    website: String,
+    telegram: String,
+    twitter: String,
+    image_uri: String,
+    banner_uri: String,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    validate_token_socials(&description, &website, &telegram, &twitter)?;
+    require!(
+        (image_uri.is_empty() || is_valid_uri(&image_uri)) && (banner_uri.is_empty() || is_valid_uri(&banner_uri)),
+        CurveLaunchpadError::InvalidUriScheme
+    );
+
+    let token_profile = &mut ctx.accounts.token_profile;
+    token_profile.set_fields(description, website, telegram, twitter, image_uri, banner_uri)?;
+    token_profile.update_time = now as u64;
+
+    let update_event = UpdateProfileEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        description: token_profile.description.clone(),
+        website: token_profile.website.clone(),
+        telegram: token_profile.telegram.clone(),
+        twitter: token_profile.twitter.clone(),
+        image_uri: token_profile.image_uri.clone(),
+        banner_uri: token_profile.banner_uri.clone(),
+        timestamp: now,
+    };
+
+    let serialized = serde_json::to_string(&update_event).unwrap();
+
+    msg!("updateProfile:{}", serialized);
+
+    Ok(())
+}
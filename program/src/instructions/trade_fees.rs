@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    calculate_fee, distribute_invite_fee,
+    state::{BondingCurve, FeeAccount, Global, UserInviteStats},
+    CurveLaunchpadError, MAX_INVITE_LEVELS,
+};
+
+//fee rates of one trade, the protocol rate is discounted by the trader's volume tier
+pub struct TradeFees {
+    pub fee_tier: u8,
+    pub protocol_fee_basis_points: u64,
+    pub creator_fee_basis_points: u64,
+    pub invite_fee_basis_points: u64,
+}
+
+impl TradeFees {
+    pub fn new(
+        global: &Global,
+        bonding_curve: &BondingCurve,
+        trader_invite_account: &UserInviteStats,
+        is_buy: bool,
+        now: i64,
+    ) -> Self {
+        let (fee_tier, protocol_fee_basis_points) =
+            global.discounted_fee_basis_points(trader_invite_account.rolling_volume(now));
+
+        TradeFees {
+            fee_tier,
+            protocol_fee_basis_points,
+            creator_fee_basis_points: bonding_curve.creator_fee_basis_points(is_buy, now),
+            invite_fee_basis_points: global.invite_fee_basis_points,
+        }
+    }
+
+    pub fn total_basis_points(&self) -> u64 {
+        self.protocol_fee_basis_points + self.creator_fee_basis_points + self.invite_fee_basis_points
+    }
+
+    pub fn fee(&self, sol_amount: u64) -> u64 {
+        calculate_fee(sol_amount, self.total_basis_points())
+    }
+
+    //splits the fee into its (protocol, creator, invite) shares. the protocol takes what the creator and
+    //invite shares leave, so the rounding dust is booked too
+    pub fn split(&self, sol_amount: u64) -> Result<(u64, u64, u64)> {
+        let creator_fee = calculate_fee(sol_amount, self.creator_fee_basis_points);
+        let invite_fee = calculate_fee(sol_amount, self.invite_fee_basis_points);
+        let protocol_fee = self
+            .fee(sol_amount)
+            .checked_sub(creator_fee + invite_fee)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+
+        Ok((protocol_fee, creator_fee, invite_fee))
+    }
+}
+
+//records the fee of a trade once its lamports are in the fee account and books it.
+//every buy and sell path settles through here
+#[allow(clippy::too_many_arguments)]
+pub fn settle_trade_fee<'info>(
+    global: &Global,
+    fees: &TradeFees,
+    sol_amount: u64,
+    bonding_curve: &mut BondingCurve,
+    trader_invite_account: &mut UserInviteStats,
+    chain: [Option<&mut Account<'info, UserInviteStats>>; MAX_INVITE_LEVELS],
+    fee_recipient_invite_account: &mut UserInviteStats,
+    fee_account: &mut Account<'info, FeeAccount>,
+    program_id: &Pubkey,
+) -> Result<u64> {
+    fee_account.received += fees.fee(sol_amount);
+    let is_ok = fee_account.check(fee_account.get_lamports());
+    require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+
+    book_trade_fee(
+        global,
+        fees,
+        sol_amount,
+        bonding_curve,
+        trader_invite_account,
+        chain,
+        fee_recipient_invite_account,
+        program_id,
+    )
+}
+
+//books the fee of a trade already recorded in the fee account: the protocol share, the creator
+//share (to the protocol once renounced), the invite share along the trader's referral chain and the
+//trader's volume for the fee tiers. a batch records the fees of all its legs at once and books each leg
+#[allow(clippy::too_many_arguments)]
+pub fn book_trade_fee<'info>(
+    global: &Global,
+    fees: &TradeFees,
+    sol_amount: u64,
+    bonding_curve: &mut BondingCurve,
+    trader_invite_account: &mut UserInviteStats,
+    chain: [Option<&mut Account<'info, UserInviteStats>>; MAX_INVITE_LEVELS],
+    fee_recipient_invite_account: &mut UserInviteStats,
+    program_id: &Pubkey,
+) -> Result<u64> {
+    //the invite share is distributed along the chain below
+    let (protocol_fee, creator_fee, _) = fees.split(sol_amount)?;
+
+    fee_recipient_invite_account.profit_claimable += protocol_fee;
+    if bonding_curve.creator_fee_renounced {
+        fee_recipient_invite_account.profit_claimable += creator_fee;
+    } else {
+        bonding_curve.creator_fee_claimable += creator_fee;
+    }
+
+    let invite_fee = distribute_invite_fee(
+        global,
+        sol_amount,
+        trader_invite_account,
+        chain,
+        fee_recipient_invite_account,
+        program_id,
+    )?;
+
+    trader_invite_account.record_volume(Clock::get()?.unix_timestamp, sol_amount);
+
+    Ok(invite_fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let fees = TradeFees {
+            fee_tier: 0,
+            protocol_fee_basis_points: 95,
+            creator_fee_basis_points: 33,
+            invite_fee_basis_points: 17,
+        };
+
+        assert_eq!(fees.split(1_000_000).unwrap(), (9_500, 3_300, 1_700));
+        //each share rounds down on its own, the protocol collects the dust
+        let (protocol_fee, creator_fee, invite_fee) = fees.split(999).unwrap();
+        assert_eq!((creator_fee, invite_fee), (3, 1));
+        assert_eq!(protocol_fee + creator_fee + invite_fee, fees.fee(999));
+        assert_eq!(protocol_fee, 10);
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{state::BondingCurve, AcceptCreatorEvent, TransferCreatorEvent};
+
+#[derive(Accounts)]
+pub struct TransferCreator<'info> {
+    creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+//Pubkey::default() cancels a pending transfer
+pub fn transfer_creator(ctx: Context<TransferCreator>, new_creator: Pubkey) -> Result<()> {
+    ctx.accounts.bonding_curve.pending_creator = new_creator;
+
+    let transfer_event = TransferCreatorEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        creator: ctx.accounts.creator.key().to_string(),
+        pending_creator: new_creator.to_string(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&transfer_event).unwrap();
+
+    msg!("transferCreator:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptCreator<'info> {
+    new_creator: Signer<'info>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+pub fn accept_creator(ctx: Context<AcceptCreator>) -> Result<()> {
+    let new_creator = ctx.accounts.new_creator.key();
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    let old_creator = bonding_curve.accept_creator(new_creator)?;
+
+    let accept_event = AcceptCreatorEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        old_creator: old_creator.to_string(),
+        new_creator: new_creator.to_string(),
+        creator_fee_recipient: bonding_curve.creator_fee_recipient.to_string(),
+        creator_fee_claimable: bonding_curve.creator_fee_claimable,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&accept_event).unwrap();
+
+    msg!("acceptCreator:{}", serialized);
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hash};
 
-use crate::state::{FeeAccount, UserInviteStats};
-use crate::{ClaimInviteProfitEvent, CurveLaunchpadError};
+use crate::state::{FeeAccount, Global, InviteCode, UserInviteStats};
+use crate::{
+    calculate_fee, is_valid_invite_code, ClaimInviteProfitEvent, CurveLaunchpadError,
+    RebindInviteEvent, RegisterInviteCodeEvent, INVITE_REBIND_GRACE_SECONDS, MAX_INVITE_CODE_LEN,
+    MAX_INVITE_LEVELS, MIN_INVITE_CODE_LEN,
+};
 
 
 
@@ -32,32 +36,82 @@ pub struct InitInviteAccount<'info> {
     system_program: Program<'info, System>,
 }
 
+//the invite accounts of the parent's ancestors, up to its root, are passed in the remaining accounts
 pub fn init_invite(ctx: Context<InitInviteAccount>, parent: Pubkey) -> Result<()> {
+    bind_parent(
+        &ctx.accounts.user,
+        &mut ctx.accounts.user_invite_account,
+        &mut ctx.accounts.parent_invite_account,
+        ctx.remaining_accounts,
+        parent,
+        ctx.program_id,
+    )
+}
+
+fn bind_parent<'info>(
+    user: &Signer<'info>,
+    user_invite_account: &mut Account<'info, UserInviteStats>,
+    parent_invite_account: &mut Account<'info, UserInviteStats>,
+    ancestors: &[AccountInfo],
+    parent: Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
 
 
-    require_keys_neq!(ctx.accounts.user_invite_account.key(), ctx.accounts.parent_invite_account.key(), CurveLaunchpadError::InviteAccountError);
+    require_keys_neq!(user_invite_account.key(), parent_invite_account.key(), CurveLaunchpadError::InviteAccountError);
 
 
-    if ctx.accounts.user_invite_account.is_init {
+    if user_invite_account.is_init {
         return Ok(());
     }
-    ctx.accounts.user_invite_account.is_init = true;
-    if !ctx.accounts.parent_invite_account.is_init {
-        ctx.accounts.parent_invite_account.is_init = true;
+
+    check_invite_cycle(user.key(), parent_invite_account, ancestors, program_id)?;
+
+    user_invite_account.is_init = true;
+    if !parent_invite_account.is_init {
+        parent_invite_account.is_init = true;
     }
 
-    ctx.accounts.user_invite_account.key = ctx.accounts.user.key();
-    ctx.accounts.user_invite_account.parent = parent;
-    ctx.accounts.parent_invite_account.child_count += 1;
+    user_invite_account.key = user.key();
+    user_invite_account.parent = parent;
+    user_invite_account.bind_time = Clock::get()?.unix_timestamp as u64;
+    parent_invite_account.child_count += 1;
 
     Ok(())
 }
 
+//walks the ancestry of the parent up to its root and rejects binding the user below one of
+//its own descendants. the ancestors' invite accounts are supplied in order, starting at the grandparent
+fn check_invite_cycle(
+    user: Pubkey,
+    parent_invite_account: &UserInviteStats,
+    ancestors: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let mut ancestor = parent_invite_account.parent;
+    let mut ancestor_infos = ancestors.iter();
+
+    while ancestor != Pubkey::default() {
+        require_keys_neq!(ancestor, user, CurveLaunchpadError::InviteCycle);
+
+        let info = ancestor_infos.next().ok_or(CurveLaunchpadError::InviteAccountNotInit)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[UserInviteStats::SEED_PREFIX, ancestor.as_ref()],
+            program_id,
+        );
+        require_keys_eq!(info.key(), expected, CurveLaunchpadError::InviteAccountError);
+        require_keys_eq!(*info.owner, *program_id, CurveLaunchpadError::InviteAccountError);
+
+        ancestor = UserInviteStats::try_deserialize(&mut &info.try_borrow_data()?[..])?.parent;
+    }
 
+    Ok(())
+}
 
 
 #[derive(Accounts)]
-pub struct InviteClaimAccount<'info> {
+#[instruction(new_parent: Pubkey)]
+pub struct RebindInviteAccount<'info> {
     #[account(mut)]
     user: Signer<'info>,
 
@@ -68,6 +122,184 @@ pub struct InviteClaimAccount<'info> {
     )]
     user_invite_account: Box<Account<'info, UserInviteStats>>,
 
+    #[account(
+        mut,
+        seeds=[UserInviteStats::SEED_PREFIX,user_invite_account.parent.as_ref()],
+        bump
+    )]
+    old_parent_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,new_parent.as_ref()],
+        bump
+    )]
+    new_parent_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    system_program: Program<'info, System>,
+}
+
+//a bound user can move to another parent once, within the grace period after binding.
+//the invite accounts of the new parent's ancestors are passed in the remaining accounts
+pub fn rebind_invite(ctx: Context<RebindInviteAccount>, new_parent: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let user_invite_account = &ctx.accounts.user_invite_account;
+
+    require!(user_invite_account.is_init, CurveLaunchpadError::InviteAccountNotInit);
+    require!(
+        !user_invite_account.rebound
+            && now as u64 <= user_invite_account.bind_time + INVITE_REBIND_GRACE_SECONDS,
+        CurveLaunchpadError::InviteRebindNotAllowed
+    );
+    require_keys_neq!(user_invite_account.key(), ctx.accounts.new_parent_invite_account.key(), CurveLaunchpadError::InviteAccountError);
+    require_keys_neq!(user_invite_account.parent, new_parent, CurveLaunchpadError::InviteAccountError);
+
+    check_invite_cycle(
+        ctx.accounts.user.key(),
+        &ctx.accounts.new_parent_invite_account,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    let old_parent = user_invite_account.parent;
+
+    ctx.accounts.old_parent_invite_account.child_count =
+        ctx.accounts.old_parent_invite_account.child_count.saturating_sub(1);
+    if !ctx.accounts.new_parent_invite_account.is_init {
+        ctx.accounts.new_parent_invite_account.is_init = true;
+    }
+    ctx.accounts.new_parent_invite_account.child_count += 1;
+
+    ctx.accounts.user_invite_account.parent = new_parent;
+    ctx.accounts.user_invite_account.rebound = true;
+
+    let rebind_event = RebindInviteEvent {
+        user: ctx.accounts.user.to_account_info().key().to_string(),
+        old_parent: old_parent.to_string(),
+        new_parent: new_parent.to_string(),
+        timestamp: now,
+    };
+
+    let serialized = serde_json::to_string(&rebind_event).unwrap();
+
+    msg!("rebindInvite:{}", serialized);
+
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct RegisterInviteCode<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        init,
+        space = 8 + InviteCode::INIT_SPACE,
+        payer = user,
+        seeds=[InviteCode::SEED_PREFIX, hash(code.as_bytes()).as_ref()],
+        bump
+    )]
+    invite_code: Box<Account<'info, InviteCode>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn register_invite_code(ctx: Context<RegisterInviteCode>, code: String) -> Result<()> {
+    require!(
+        is_valid_invite_code(&code, MIN_INVITE_CODE_LEN, MAX_INVITE_CODE_LEN),
+        CurveLaunchpadError::InvalidInviteCode
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.invite_code.owner = ctx.accounts.user.key();
+    ctx.accounts.invite_code.code = code.clone();
+    ctx.accounts.invite_code.create_time = timestamp as u64;
+
+    let register_event = RegisterInviteCodeEvent {
+        user: ctx.accounts.user.to_account_info().key().to_string(),
+        code,
+        timestamp,
+    };
+
+    let serialized = serde_json::to_string(&register_event).unwrap();
+
+    msg!("registerInviteCode:{}", serialized);
+
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct InitInviteAccountWithCode<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        seeds=[InviteCode::SEED_PREFIX, hash(code.as_bytes()).as_ref()],
+        bump
+    )]
+    invite_code: Box<Account<'info, InviteCode>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
+        bump
+    )]
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + UserInviteStats::INIT_SPACE,
+        payer = user,
+        seeds=[UserInviteStats::SEED_PREFIX,invite_code.owner.as_ref()],
+        bump
+    )]
+    parent_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    system_program: Program<'info, System>,
+}
+
+//the invite accounts of the code owner's ancestors, up to its root, are passed in the remaining accounts
+pub fn init_invite_with_code(ctx: Context<InitInviteAccountWithCode>, _code: String) -> Result<()> {
+    let parent = ctx.accounts.invite_code.owner;
+    bind_parent(
+        &ctx.accounts.user,
+        &mut ctx.accounts.user_invite_account,
+        &mut ctx.accounts.parent_invite_account,
+        ctx.remaining_accounts,
+        parent,
+        ctx.program_id,
+    )
+}
+
+
+
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct InviteClaimAccount<'info> {
+    //the owner of the invite account or its claim delegate
+    #[account(mut)]
+    claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[UserInviteStats::SEED_PREFIX,owner.as_ref()],
+        bump
+    )]
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    ///CHECK: any account chosen by the claimer can receive the claimed lamports
+    #[account(mut)]
+    destination: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -78,19 +310,17 @@ pub struct InviteClaimAccount<'info> {
 }
 
 
-pub fn claim_inivte_profit(ctx: Context<InviteClaimAccount>) -> Result<()> {
+pub fn claim_inivte_profit(ctx: Context<InviteClaimAccount>, owner: Pubkey, amount: Option<u64>) -> Result<()> {
 
-    let claim_amount = ctx.accounts.user_invite_account.profit_claimable;
-    require!(claim_amount > 0,CurveLaunchpadError::NotClaimableFee);
-    ctx.accounts.user_invite_account.profit_claimable = 0;
-    ctx.accounts.user_invite_account.profit_claim_accumulated += claim_amount;
+    let claimer = ctx.accounts.claimer.key();
+    let claim_amount = ctx.accounts.user_invite_account.claim_profit(claimer, owner, amount)?;
 
     let fee_account = &ctx.accounts.fee_account;
-    let user_account = &ctx.accounts.user;
+    let destination = &ctx.accounts.destination;
 
 
     **fee_account.to_account_info().try_borrow_mut_lamports()? -= claim_amount;
-    **user_account.try_borrow_mut_lamports()? += claim_amount;
+    **destination.try_borrow_mut_lamports()? += claim_amount;
 
     ctx.accounts.fee_account.sent += claim_amount;
     let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
@@ -98,9 +328,11 @@ pub fn claim_inivte_profit(ctx: Context<InviteClaimAccount>) -> Result<()> {
     
 
     let claim_event = ClaimInviteProfitEvent{
-        user: ctx.accounts.user.to_account_info().key().to_string(),
+        user: owner.to_string(),
         amount: claim_amount,
         timestamp: Clock::get()?.unix_timestamp,
+        claimer: claimer.to_string(),
+        destination: ctx.accounts.destination.key().to_string(),
     };
 
     let serialized = serde_json::to_string(&claim_event).unwrap();
@@ -109,4 +341,325 @@ pub fn claim_inivte_profit(ctx: Context<InviteClaimAccount>) -> Result<()> {
 
 
     Ok(())
-}
\ No newline at end of file
+}
+
+
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[UserInviteStats::SEED_PREFIX,user.key().as_ref()],
+        bump
+    )]
+    user_invite_account: Box<Account<'info, UserInviteStats>>,
+}
+
+//Pubkey::default() removes the delegate
+pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, claim_delegate: Pubkey) -> Result<()> {
+    ctx.accounts.user_invite_account.claim_delegate = claim_delegate;
+
+    Ok(())
+}
+
+
+//splits the invite fee of a trade along the referral chain, starting at the direct parent.
+//the share of a level without a referrer, and the rounding dust of the split, go to the fee recipient.
+//every invite account is loaded once per trade, a second copy would overwrite the first one's credit
+//on exit. a level referred by the fee recipient is therefore not supplied and credited to its account
+pub fn distribute_invite_fee<'info>(
+    global: &Global,
+    sol_amount: u64,
+    user_invite_account: &mut UserInviteStats,
+    mut chain: [Option<&mut Account<'info, UserInviteStats>>; MAX_INVITE_LEVELS],
+    fee_recipient_invite_account: &mut UserInviteStats,
+    program_id: &Pubkey,
+) -> Result<u64> {
+    let mut referrer = user_invite_account.parent;
+    let mut referrers = [Pubkey::default(); MAX_INVITE_LEVELS];
+    let mut invite_fee = 0;
+    let mut remainder = calculate_fee(sol_amount, global.invite_fee_basis_points);
+
+    for (level, ancestor) in chain.iter_mut().enumerate() {
+        let level_fee = calculate_fee(sol_amount, global.invite_level_basis_points[level]);
+        if level_fee == 0 {
+            break;
+        }
+        remainder -= level_fee;
+
+        if referrer == Pubkey::default() {
+            fee_recipient_invite_account.profit_claimable += level_fee;
+            continue;
+        }
+
+        require!(
+            referrer != user_invite_account.key && !referrers[..level].contains(&referrer),
+            CurveLaunchpadError::InviteCycle
+        );
+        referrers[level] = referrer;
+
+        if referrer == global.fee_recipient {
+            require!(ancestor.is_none(), CurveLaunchpadError::InviteAccountError);
+            referrer = fee_recipient_invite_account.parent;
+
+            fee_recipient_invite_account.profit_claimable += level_fee;
+            fee_recipient_invite_account.profit_from_child += level_fee;
+            fee_recipient_invite_account.profit_from_level[level] += level_fee;
+            invite_fee += level_fee;
+            continue;
+        }
+
+        let ancestor = ancestor.as_mut().ok_or(CurveLaunchpadError::InviteAccountNotInit)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[UserInviteStats::SEED_PREFIX, referrer.as_ref()],
+            program_id,
+        );
+        require_keys_eq!(ancestor.key(), expected, CurveLaunchpadError::InviteAccountError);
+        referrer = ancestor.parent;
+
+        if global.invite_requires_trade && !ancestor.has_traded {
+            fee_recipient_invite_account.profit_claimable += level_fee;
+            continue;
+        }
+
+        ancestor.profit_claimable += level_fee;
+        ancestor.profit_from_child += level_fee;
+        ancestor.profit_from_level[level] += level_fee;
+        invite_fee += level_fee;
+    }
+
+    fee_recipient_invite_account.profit_claimable += remainder;
+    user_invite_account.profit_to_parent += invite_fee;
+
+    Ok(invite_fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invite_account_key(owner: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[UserInviteStats::SEED_PREFIX, owner.as_ref()], &crate::ID).0
+    }
+
+    fn invite_account_data(owner: Pubkey, parent: Pubkey) -> Vec<u8> {
+        let mut data = Vec::new();
+        UserInviteStats { key: owner, parent, is_init: true, ..Default::default() }
+            .try_serialize(&mut data)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_distribute_invite_fee() {
+        let global = Global {
+            invite_fee_basis_points: 180,
+            invite_level_basis_points: [100, 50, 30],
+            ..Default::default()
+        };
+
+        //user -> parent -> grandparent, the grandparent has no referrer
+        let parent = Pubkey::new_unique();
+        let grandparent = Pubkey::new_unique();
+        let mut user_invite_account = UserInviteStats { parent, ..Default::default() };
+        let mut fee_recipient_invite_account = UserInviteStats::default();
+
+        let parent_key = invite_account_key(&parent);
+        let mut parent_lamports = 0;
+        let mut parent_data = invite_account_data(parent, grandparent);
+        let parent_info = AccountInfo::new(
+            &parent_key, false, true, &mut parent_lamports, &mut parent_data, &crate::ID, false, 0,
+        );
+        let mut parent_account = Account::<UserInviteStats>::try_from(&parent_info).unwrap();
+
+        let grandparent_key = invite_account_key(&grandparent);
+        let mut grandparent_lamports = 0;
+        let mut grandparent_data = invite_account_data(grandparent, Pubkey::default());
+        let grandparent_info = AccountInfo::new(
+            &grandparent_key, false, true, &mut grandparent_lamports, &mut grandparent_data, &crate::ID, false, 0,
+        );
+        let mut grandparent_account = Account::<UserInviteStats>::try_from(&grandparent_info).unwrap();
+
+        let invite_fee = distribute_invite_fee(
+            &global,
+            10_000,
+            &mut user_invite_account,
+            [Some(&mut parent_account), Some(&mut grandparent_account), None],
+            &mut fee_recipient_invite_account,
+            &crate::ID,
+        )
+        .unwrap();
+
+        assert_eq!(invite_fee, 150);
+        assert_eq!(user_invite_account.profit_to_parent, 150);
+        assert_eq!(parent_account.profit_claimable, 100);
+        assert_eq!(parent_account.profit_from_level, [100, 0, 0]);
+        assert_eq!(grandparent_account.profit_claimable, 50);
+        assert_eq!(grandparent_account.profit_from_level, [0, 50, 0]);
+        //the third level has no referrer
+        assert_eq!(fee_recipient_invite_account.profit_claimable, 30);
+    }
+
+    #[test]
+    fn test_distribute_invite_fee_without_referrer() {
+        let global = Global {
+            invite_fee_basis_points: 180,
+            invite_level_basis_points: [100, 50, 30],
+            ..Default::default()
+        };
+
+        //a lazily created invite account has no parent, the whole invite fee goes to the protocol
+        let mut user_invite_account = UserInviteStats::default();
+        let mut fee_recipient_invite_account = UserInviteStats::default();
+
+        let invite_fee = distribute_invite_fee(
+            &global,
+            10_000,
+            &mut user_invite_account,
+            [None, None, None],
+            &mut fee_recipient_invite_account,
+            &crate::ID,
+        )
+        .unwrap();
+
+        assert_eq!(invite_fee, 0);
+        assert_eq!(user_invite_account.profit_to_parent, 0);
+        assert_eq!(fee_recipient_invite_account.profit_claimable, 180);
+    }
+
+    #[test]
+    fn test_distribute_invite_fee_to_fee_recipient_referrer() {
+        let fee_recipient = Pubkey::new_unique();
+        let global = Global {
+            fee_recipient,
+            invite_fee_basis_points: 180,
+            invite_level_basis_points: [100, 50, 30],
+            ..Default::default()
+        };
+
+        //user -> fee recipient -> grandparent
+        let grandparent = Pubkey::new_unique();
+        let mut user_invite_account = UserInviteStats { parent: fee_recipient, ..Default::default() };
+        let mut fee_recipient_invite_account = UserInviteStats {
+            key: fee_recipient,
+            parent: grandparent,
+            ..Default::default()
+        };
+
+        let grandparent_key = invite_account_key(&grandparent);
+        let mut grandparent_lamports = 0;
+        let mut grandparent_data = invite_account_data(grandparent, Pubkey::default());
+        let grandparent_info = AccountInfo::new(
+            &grandparent_key, false, true, &mut grandparent_lamports, &mut grandparent_data, &crate::ID, false, 0,
+        );
+        let mut grandparent_account = Account::<UserInviteStats>::try_from(&grandparent_info).unwrap();
+
+        let invite_fee = distribute_invite_fee(
+            &global,
+            10_000,
+            &mut user_invite_account,
+            [None, Some(&mut grandparent_account), None],
+            &mut fee_recipient_invite_account,
+            &crate::ID,
+        )
+        .unwrap();
+
+        assert_eq!(invite_fee, 150);
+        assert_eq!(grandparent_account.profit_claimable, 50);
+        //its level share and the unreferred third level
+        assert_eq!(fee_recipient_invite_account.profit_claimable, 130);
+        assert_eq!(fee_recipient_invite_account.profit_from_level, [100, 0, 0]);
+    }
+
+    #[test]
+    fn test_distribute_invite_fee_rejects_duplicates() {
+        let fee_recipient = Pubkey::new_unique();
+        let global = Global {
+            fee_recipient,
+            invite_fee_basis_points: 150,
+            invite_level_basis_points: [100, 50, 0],
+            ..Default::default()
+        };
+
+        //a second copy of the fee recipient's account in the chain
+        let fee_recipient_key = invite_account_key(&fee_recipient);
+        let mut fee_recipient_lamports = 0;
+        let mut fee_recipient_data = invite_account_data(fee_recipient, Pubkey::default());
+        let fee_recipient_info = AccountInfo::new(
+            &fee_recipient_key, false, true, &mut fee_recipient_lamports, &mut fee_recipient_data, &crate::ID, false, 0,
+        );
+        let mut fee_recipient_copy = Account::<UserInviteStats>::try_from(&fee_recipient_info).unwrap();
+
+        let mut user_invite_account = UserInviteStats { parent: fee_recipient, ..Default::default() };
+        let result = distribute_invite_fee(
+            &global,
+            10_000,
+            &mut user_invite_account,
+            [Some(&mut fee_recipient_copy), None, None],
+            &mut UserInviteStats::default(),
+            &crate::ID,
+        );
+        assert_eq!(result.unwrap_err(), CurveLaunchpadError::InviteAccountError.into());
+
+        //a legacy cycle leading back to the trader
+        let user = Pubkey::new_unique();
+        let parent = Pubkey::new_unique();
+        let parent_key = invite_account_key(&parent);
+        let mut parent_lamports = 0;
+        let mut parent_data = invite_account_data(parent, user);
+        let parent_info = AccountInfo::new(
+            &parent_key, false, true, &mut parent_lamports, &mut parent_data, &crate::ID, false, 0,
+        );
+        let mut parent_account = Account::<UserInviteStats>::try_from(&parent_info).unwrap();
+
+        let mut user_invite_account = UserInviteStats { key: user, parent, ..Default::default() };
+        let result = distribute_invite_fee(
+            &global,
+            10_000,
+            &mut user_invite_account,
+            [Some(&mut parent_account), None, None],
+            &mut UserInviteStats::default(),
+            &crate::ID,
+        );
+        assert_eq!(result.unwrap_err(), CurveLaunchpadError::InviteCycle.into());
+    }
+
+    #[test]
+    fn test_check_invite_cycle() {
+        //parent -> a -> b -> c -> root
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let parent_invite_account = UserInviteStats { parent: a, ..Default::default() };
+
+        let keys = [invite_account_key(&a), invite_account_key(&b), invite_account_key(&c)];
+        let mut lamports = [0u64; 3];
+        let mut data = [
+            invite_account_data(a, b),
+            invite_account_data(b, c),
+            invite_account_data(c, Pubkey::default()),
+        ];
+        let ancestors: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| AccountInfo::new(key, false, false, lamports, data, &crate::ID, false, 0))
+            .collect();
+
+        assert!(check_invite_cycle(Pubkey::new_unique(), &parent_invite_account, &ancestors, &crate::ID).is_ok());
+        //the root binding below its great-great-grandchild
+        assert_eq!(
+            check_invite_cycle(c, &parent_invite_account, &ancestors, &crate::ID).unwrap_err(),
+            CurveLaunchpadError::InviteCycle.into()
+        );
+        //the ancestry must be supplied up to the root
+        assert_eq!(
+            check_invite_cycle(Pubkey::new_unique(), &parent_invite_account, &ancestors[..2], &crate::ID).unwrap_err(),
+            CurveLaunchpadError::InviteAccountNotInit.into()
+        );
+        //in order
+        assert_eq!(
+            check_invite_cycle(Pubkey::new_unique(), &parent_invite_account, &ancestors[1..], &crate::ID).unwrap_err(),
+            CurveLaunchpadError::InviteAccountError.into()
+        );
+    }
+}
@@ -6,6 +6,179 @@ pub fn calculate_fee(
     amount * fee_basis_points / 10000
 }
 
+pub fn calculate_decayed_basis_points(
+    start_basis_points: u64,
+    end_basis_points: u64,
+    decay_seconds: u64,
+    elapsed: u64,
+) -> u64 {
+    if elapsed >= decay_seconds {
+        return end_basis_points;
+    }
+
+    let progress = (elapsed as u128, decay_seconds as u128);
+    if start_basis_points >= end_basis_points {
+        let delta = (start_basis_points - end_basis_points) as u128;
+        start_basis_points - (delta * progress.0 / progress.1) as u64
+    } else {
+        let delta = (end_basis_points - start_basis_points) as u128;
+        start_basis_points + (delta * progress.0 / progress.1) as u64
+    }
+}
+
+//no deadline means the trade never goes stale
+pub fn is_within_deadline(now: i64, deadline: Option<i64>) -> bool {
+    deadline.is_none_or(|deadline| now <= deadline)
+}
+
+//a buy must not push the marginal price above the limit, a sell must not push it below
+pub fn is_within_price_limit(price_after: u128, limit_price: Option<u64>, is_buy: bool) -> bool {
+    limit_price.is_none_or(|limit_price| {
+        if is_buy {
+            price_after <= limit_price as u128
+        } else {
+            price_after >= limit_price as u128
+        }
+    })
+}
+
+//sum of the daily buckets that are still inside the window as of `day`
+pub fn rolling_volume(buckets: &[u64], last_day: u64, day: u64) -> u64 {
+    let window = buckets.len() as u64;
+    if day >= last_day + window {
+        return 0;
+    }
+
+    let first_day = (day + 1).saturating_sub(window);
+    (first_day..=last_day)
+        .map(|d| buckets[(d % window) as usize])
+        .sum()
+}
+
+//clears the buckets of the days elapsed since `last_day` and adds the amount to `day`
+pub fn record_volume(buckets: &mut [u64], last_day: u64, day: u64, amount: u64) {
+    let window = buckets.len() as u64;
+    if day > last_day {
+        let cleared = (day - last_day).min(window);
+        for d in (day + 1 - cleared)..=day {
+            buckets[(d % window) as usize] = 0;
+        }
+    }
+
+    let index = (day.max(last_day) % window) as usize;
+    buckets[index] = buckets[index].saturating_add(amount);
+}
+
+//invite codes are upper case letters and digits, e.g. KIMI42
+pub fn is_valid_invite_code(code: &str, min_len: usize, max_len: usize) -> bool {
+    code.len() >= min_len
+        && code.len() <= max_len
+        && code.bytes().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+//rejects control characters, newlines are only allowed where multi line text makes sense
+pub fn is_printable(text: &str, allow_newline: bool) -> bool {
+    text.chars().all(|c| !c.is_control() || (allow_newline && c == '\n'))
+}
+
+//https, ipfs and arweave links with a non empty location and no whitespace
+pub fn is_valid_uri(uri: &str) -> bool {
+    ["https://", "ipfs://", "ar://"].iter().any(|scheme| {
+        uri.strip_prefix(scheme)
+            .is_some_and(|rest| !rest.is_empty() && !rest.chars().any(|c| c.is_whitespace()))
+    })
+}
+
+//a social handle with an optional leading @
+pub fn is_valid_social_handle(handle: &str, min_len: usize, max_len: usize) -> bool {
+    let handle = handle.strip_prefix('@').unwrap_or(handle);
+    handle.len() >= min_len
+        && handle.len() <= max_len
+        && handle.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'_')
+}
+
+pub const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+//base58 of a 32 byte address into a stack buffer, returns the encoded length
+pub fn encode_base58_address(bytes: &[u8; 32], out: &mut [u8; 44]) -> usize {
+    let mut digits = [0u8; 44];
+    let mut len = 0;
+    for &byte in bytes.iter() {
+        let mut carry = byte as u32;
+        for digit in digits[..len].iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits[len] = (carry % 58) as u8;
+            len += 1;
+            carry /= 58;
+        }
+    }
+
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    for c in out[..zeros].iter_mut() {
+        *c = b'1';
+    }
+    for i in 0..len {
+        out[zeros + i] = BASE58_ALPHABET[digits[len - 1 - i] as usize];
+    }
+    zeros + len
+}
+
+//an empty pattern matches every address
+pub fn matches_vanity(address: &[u8], pattern: &[u8], is_prefix: bool, case_sensitive: bool) -> bool {
+    if pattern.len() > address.len() {
+        return false;
+    }
+    let part = if is_prefix {
+        &address[..pattern.len()]
+    } else {
+        &address[address.len() - pattern.len()..]
+    };
+    if case_sensitive {
+        part == pattern
+    } else {
+        part.eq_ignore_ascii_case(pattern)
+    }
+}
+
+//walks the proof from the leaf up, each pair is hashed in sorted order so proofs carry no side flags
+pub fn verify_merkle_proof<F>(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32], hash_pair: F) -> bool
+where
+    F: Fn(&[u8; 32], &[u8; 32]) -> [u8; 32],
+{
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        node = if node <= *sibling {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+    }
+    node == *root
+}
+
+pub fn calculate_vested_amount(
+    total_amount: u64,
+    start_time: u64,
+    cliff_time: u64,
+    end_time: u64,
+    now: u64,
+) -> u64 {
+    if now < cliff_time {
+        return 0;
+    }
+    if now >= end_time || end_time <= start_time {
+        return total_amount;
+    }
+
+    let elapsed = (now - start_time) as u128;
+    let duration = (end_time - start_time) as u128;
+    (total_amount as u128 * elapsed / duration) as u64
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -19,4 +192,168 @@ mod tests {
         assert_eq!(calculate_fee(1000, 50), 5); //0.5% fee
         assert_eq!(calculate_fee(100, 0), 0); //0% fee
     }
+
+    #[test]
+    fn test_is_within_deadline() {
+        assert!(is_within_deadline(100, None));
+        assert!(is_within_deadline(100, Some(100)));
+        assert!(is_within_deadline(99, Some(100)));
+        assert!(!is_within_deadline(101, Some(100)));
+    }
+
+    #[test]
+    fn test_is_within_price_limit() {
+        //buys are capped
+        assert!(is_within_price_limit(500, None, true));
+        assert!(is_within_price_limit(500, Some(500), true));
+        assert!(!is_within_price_limit(501, Some(500), true));
+        //sells are floored
+        assert!(is_within_price_limit(500, None, false));
+        assert!(is_within_price_limit(500, Some(500), false));
+        assert!(!is_within_price_limit(499, Some(500), false));
+    }
+
+    #[test]
+    fn test_calculate_decayed_basis_points() {
+        //sell tax decaying from 10% to 0.35% over 100 seconds
+        assert_eq!(calculate_decayed_basis_points(1000, 35, 100, 0), 1000);
+        assert_eq!(calculate_decayed_basis_points(1000, 35, 100, 50), 518);
+        assert_eq!(calculate_decayed_basis_points(1000, 35, 100, 100), 35);
+        assert_eq!(calculate_decayed_basis_points(1000, 35, 100, 1000), 35);
+        //increasing schedule
+        assert_eq!(calculate_decayed_basis_points(0, 100, 100, 25), 25);
+        //flat schedule
+        assert_eq!(calculate_decayed_basis_points(35, 35, 0, 0), 35);
+    }
+
+    #[test]
+    fn test_rolling_volume() {
+        let mut buckets = [0u64; 3];
+
+        record_volume(&mut buckets, 0, 10, 100);
+        record_volume(&mut buckets, 10, 10, 50);
+        assert_eq!(rolling_volume(&buckets, 10, 10), 150);
+
+        record_volume(&mut buckets, 10, 11, 20);
+        assert_eq!(rolling_volume(&buckets, 11, 12), 170);
+        //day 10 falls out of the window
+        assert_eq!(rolling_volume(&buckets, 11, 13), 20);
+        assert_eq!(rolling_volume(&buckets, 11, 14), 0);
+
+        //a stale bucket is reset before it is reused
+        record_volume(&mut buckets, 11, 13, 5);
+        assert_eq!(buckets, [0, 5, 20]);
+        assert_eq!(rolling_volume(&buckets, 13, 13), 25);
+    }
+
+    #[test]
+    fn test_is_valid_invite_code() {
+        assert!(is_valid_invite_code("KIMI42", 3, 16));
+        assert!(is_valid_invite_code("ABC", 3, 16));
+        assert!(!is_valid_invite_code("AB", 3, 16)); //too short
+        assert!(!is_valid_invite_code("ABCDEFGHIJKLMNOPQ", 3, 16)); //too long
+        assert!(!is_valid_invite_code("kimi42", 3, 16)); //lower case
+        assert!(!is_valid_invite_code("KIMI 42", 3, 16)); //space
+        assert!(!is_valid_invite_code("KIMI42É", 3, 16)); //non ascii
+    }
+
+    #[test]
+    fn test_is_printable() {
+        assert!(is_printable("Doge Moon 🚀", false));
+        assert!(!is_printable("Doge\u{0}Moon", false)); //null byte
+        assert!(!is_printable("line\nbreak", false));
+        assert!(is_printable("line\nbreak", true));
+        assert!(!is_printable("tab\tstop", true));
+    }
+
+    #[test]
+    fn test_is_valid_uri() {
+        assert!(is_valid_uri("https://example.com/meta.json"));
+        assert!(is_valid_uri("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"));
+        assert!(is_valid_uri("ar://abc123"));
+        assert!(!is_valid_uri("http://example.com")); //plain http
+        assert!(!is_valid_uri("https://")); //no location
+        assert!(!is_valid_uri("https://exa mple.com")); //whitespace
+        assert!(!is_valid_uri("javascript:alert(1)"));
+    }
+
+    #[test]
+    fn test_is_valid_social_handle() {
+        assert!(is_valid_social_handle("@moon_dev", 1, 15));
+        assert!(is_valid_social_handle("moon_dev", 1, 15));
+        assert!(!is_valid_social_handle("@", 1, 15)); //empty
+        assert!(!is_valid_social_handle("moon", 5, 32)); //too short for telegram
+        assert!(!is_valid_social_handle("a_very_long_handle", 1, 15)); //too long
+        assert!(!is_valid_social_handle("moon-dev", 1, 15)); //dash
+    }
+
+    #[test]
+    fn test_encode_base58_address() {
+        let mut out = [0u8; 44];
+        let len = encode_base58_address(&[0u8; 32], &mut out);
+        assert_eq!(&out[..len], b"11111111111111111111111111111111");
+
+        let mut bytes = [0u8; 32];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let len = encode_base58_address(&bytes, &mut out);
+        assert_eq!(&out[..len], b"1thX6LZfHDZZKUs92febYZhYRcXddmzfzF2NvTkPNE");
+
+        let mut bytes = [255u8; 32];
+        bytes[0] = 0;
+        bytes[1] = 0;
+        let len = encode_base58_address(&bytes, &mut out);
+        assert_eq!(&out[..len], b"11tJ93RwaVfE1PEMxd5rpZZuPtLCwbEaDCrNBhAy8Cv");
+    }
+
+    #[test]
+    fn test_matches_vanity() {
+        let address = b"FqMc4FiVt5J4oohjvXGMSTnQkW99WiPBxjdVHAFXMeMe";
+        assert!(matches_vanity(address, b"", false, true)); //no rule
+        assert!(matches_vanity(address, b"meme", false, false));
+        assert!(!matches_vanity(address, b"meme", false, true));
+        assert!(matches_vanity(address, b"MeMe", false, true));
+        assert!(matches_vanity(address, b"fqmc", true, false));
+        assert!(!matches_vanity(address, b"fqmc", true, true));
+        assert!(!matches_vanity(b"abc", b"abcd", false, false)); //pattern longer than address
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        //toy hash, only the tree walk is under test
+        let hash_pair = |a: &[u8; 32], b: &[u8; 32]| {
+            let mut out = [0u8; 32];
+            for i in 0..32 {
+                out[i] = a[i].wrapping_mul(31).wrapping_add(b[i]).wrapping_add(i as u8);
+            }
+            out
+        };
+        let sorted = |a: &[u8; 32], b: &[u8; 32]| if a <= b { hash_pair(a, b) } else { hash_pair(b, a) };
+
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]];
+        let left = sorted(&leaves[0], &leaves[1]);
+        let right = sorted(&leaves[2], &leaves[3]);
+        let root = sorted(&left, &right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], &root, leaves[0], hash_pair));
+        assert!(verify_merkle_proof(&[leaves[2], left], &root, leaves[3], hash_pair));
+        assert!(verify_merkle_proof(&[leaves[0], right], &root, leaves[1], hash_pair));
+        assert!(!verify_merkle_proof(&[leaves[2], right], &root, leaves[0], hash_pair)); //wrong sibling
+        assert!(!verify_merkle_proof(&[leaves[1], right], &root, [9u8; 32], hash_pair)); //unknown leaf
+    }
+
+    #[test]
+    fn test_calculate_vested_amount() {
+        //nothing before the cliff
+        assert_eq!(calculate_vested_amount(1000, 100, 150, 200, 120), 0);
+        //linear from the start once the cliff has passed
+        assert_eq!(calculate_vested_amount(1000, 100, 150, 200, 150), 500);
+        assert_eq!(calculate_vested_amount(1000, 100, 150, 200, 175), 750);
+        //everything after the end
+        assert_eq!(calculate_vested_amount(1000, 100, 150, 200, 200), 1000);
+        assert_eq!(calculate_vested_amount(1000, 100, 150, 200, 500), 1000);
+        //no cliff
+        assert_eq!(calculate_vested_amount(1000, 100, 100, 200, 110), 100);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{state::CreatorVesting, ClaimVestedEvent, CurveLaunchpadError};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VestingParams {
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(address = creator_vesting.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [CreatorVesting::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    creator_vesting: Box<Account<'info, CreatorVesting>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = creator_vesting,
+        seeds = [CreatorVesting::VAULT_SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    vesting_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+    )]
+    creator_token_account: Box<Account<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let claim_amount = ctx.accounts.creator_vesting.claimable_amount(now as u64);
+    require!(claim_amount > 0, CurveLaunchpadError::NoVestedTokens);
+
+    ctx.accounts.creator_vesting.claimed_amount += claim_amount;
+
+    let signer: [&[&[u8]]; 1] = [&[
+        CreatorVesting::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.creator_vesting],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vesting_token_account.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.creator_vesting.to_account_info(),
+            },
+            &signer,
+        ),
+        claim_amount,
+    )?;
+
+    let claim_event = ClaimVestedEvent {
+        creator: ctx.accounts.creator.to_account_info().key().to_string(),
+        mint: ctx.accounts.mint.to_account_info().key().to_string(),
+        amount: claim_amount,
+        claimed_amount: ctx.accounts.creator_vesting.claimed_amount,
+        total_amount: ctx.accounts.creator_vesting.total_amount,
+        timestamp: now,
+    };
+
+    let serialized = serde_json::to_string(&claim_event).unwrap();
+
+    msg!("claimVested:{}", serialized);
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, spl_token::native_mint, Mint, SyncNative, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    state::{BondingCurve, FeeAccount, Global, UserInviteStats},
+    CurveLaunchpadError, WithdrawEvent,
+};
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        address = global.withdraw_authority @ CurveLaunchpadError::InvalidWithdrawAuthority
+    )]
+    withdraw_authority: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(address = bonding_curve.mint)]
+    mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds = [BondingCurve::SEED_PREFIX, mint.key().as_ref(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdraw_authority,
+        associated_token::mint = mint,
+        associated_token::authority = withdraw_authority,
+    )]
+    withdraw_authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+//moves the liquidity of a completed curve, presale proceeds included, to the withdraw authority for the pool migration.
+//settled auction tokens and refunds stay with the curve for their participants
+pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(bonding_curve.is_complete(), CurveLaunchpadError::BondingCurveNotComplete);
+    require!(
+        bonding_curve.pool_sol_amount == 0 && bonding_curve.pool_token_amount == 0,
+        CurveLaunchpadError::AlreadyWithdraw
+    );
+
+    let token_amount = ctx
+        .accounts
+        .bonding_curve_token_account
+        .amount
+        .checked_sub(bonding_curve.unclaimed_auction_tokens())
+        .ok_or(CurveLaunchpadError::MathOverflow)?;
+    let sol_amount = bonding_curve.withdrawable_sol()?;
+
+    let signer: [&[&[u8]]; 1] = [&[
+        BondingCurve::SEED_PREFIX,
+        ctx.accounts.mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.bonding_curve],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                to: ctx.accounts.withdraw_authority_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            &signer,
+        ),
+        token_amount,
+    )?;
+
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= sol_amount;
+    **ctx.accounts.withdraw_authority.try_borrow_mut_lamports()? += sol_amount;
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_sol_reserves = 0;
+    bonding_curve.presale.sol_raised = 0;
+    bonding_curve.pool_sol_amount = sol_amount;
+    bonding_curve.pool_token_amount = token_amount;
+
+    let withdraw_event = WithdrawEvent {
+        mint: ctx.accounts.mint.key().to_string(),
+        withdraw_authority: ctx.accounts.withdraw_authority.key().to_string(),
+        sol_amount,
+        token_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    let serialized = serde_json::to_string(&withdraw_event).unwrap();
+
+    msg!("withdraw:{}", serialized);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProtocolFeeCollect<'info> {
+    #[account(
+        mut,
+        address = global.fee_recipient @ CurveLaunchpadError::InvalidFeeRecipient
+    )]
+    fee_recipient: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX],
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(
+        mut,
+        seeds = [UserInviteStats::SEED_PREFIX, fee_recipient.key().as_ref()],
+        bump
+    )]
+    fee_recipient_invite_account: Box<Account<'info, UserInviteStats>>,
+
+    #[account(
+        mut,
+        seeds = [FeeAccount::SEED_PREFIX],
+        bump
+    )]
+    fee_account: Box<Account<'info, FeeAccount>>,
+}
+
+//the protocol share of the trade fees is booked on the fee recipient's invite account
+pub fn protocol_fee_collect(ctx: Context<ProtocolFeeCollect>) -> Result<()> {
+    let claim_amount = ctx.accounts.fee_recipient_invite_account.profit_claimable;
+    require!(claim_amount > 0, CurveLaunchpadError::NotClaimableFee);
+
+    ctx.accounts.fee_recipient_invite_account.profit_claimable = 0;
+    ctx.accounts.fee_recipient_invite_account.profit_claim_accumulated += claim_amount;
+
+    **ctx.accounts.fee_account.to_account_info().try_borrow_mut_lamports()? -= claim_amount;
+    **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += claim_amount;
+
+    ctx.accounts.fee_account.sent += claim_amount;
+    let is_ok = ctx.accounts.fee_account.check(ctx.accounts.fee_account.get_lamports());
+    require!(is_ok, CurveLaunchpadError::FeeAccountStatusAbnormal);
+
+    msg!("Collected {} protocol fee lamports", claim_amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WsolSyncNative<'info> {
+    #[account(
+        mut,
+        constraint = wsol_account.mint == native_mint::ID @ CurveLaunchpadError::WrongWSOLMint
+    )]
+    wsol_account: Account<'info, TokenAccount>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn wsol_sync_native(ctx: Context<WsolSyncNative>) -> Result<()> {
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.wsol_account.to_account_info(),
+        },
+    ))
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use instructions::*;
+use state::{FeeSchedule, FeeTier, LaunchSchedule, PresaleConfig};
 
 pub mod instructions;
 pub mod state;
@@ -17,11 +18,39 @@ pub mod curve_launchpad {
         initialize::initialize(ctx)
     }
 
+    pub fn migrate_global(ctx: Context<MigrateGlobal>) -> Result<()> {
+        migrate::migrate_global(ctx)
+    }
+
+    pub fn migrate_bonding_curve(ctx: Context<MigrateBondingCurve>) -> Result<()> {
+        migrate::migrate_bonding_curve(ctx)
+    }
+
+    pub fn migrate_user_invite_stats(ctx: Context<MigrateUserInviteStats>, owner: Pubkey) -> Result<()> {
+        migrate::migrate_user_invite_stats(ctx, owner)
+    }
+
     pub fn init_invite_account(ctx:Context<InitInviteAccount>,parent: Pubkey) ->Result<()>{
         user_invite::init_invite(ctx, parent)
     }
-    pub fn claim_invite_profit(ctx:Context<InviteClaimAccount>)->Result<()>{
-        user_invite::claim_inivte_profit(ctx)
+    pub fn register_invite_code(ctx: Context<RegisterInviteCode>, code: String) -> Result<()> {
+        user_invite::register_invite_code(ctx, code)
+    }
+
+    pub fn init_invite_account_with_code(ctx: Context<InitInviteAccountWithCode>, code: String) -> Result<()> {
+        user_invite::init_invite_with_code(ctx, code)
+    }
+
+    pub fn rebind_invite_account(ctx: Context<RebindInviteAccount>, new_parent: Pubkey) -> Result<()> {
+        user_invite::rebind_invite(ctx, new_parent)
+    }
+
+    pub fn claim_invite_profit(ctx:Context<InviteClaimAccount>, owner: Pubkey, amount: Option<u64>)->Result<()>{
+        user_invite::claim_inivte_profit(ctx, owner, amount)
+    }
+
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, claim_delegate: Pubkey) -> Result<()> {
+        user_invite::set_claim_delegate(ctx, claim_delegate)
     }
 
 
@@ -29,6 +58,19 @@ pub mod curve_launchpad {
         create::init_create_account(ctx, seed)
     }
 
+    pub fn add_mint_seeds(ctx: Context<AddMintSeeds>, seeds: Vec<u64>) -> Result<()> {
+        mint_seed_pool::add_mint_seeds(ctx, seeds)
+    }
+
+    pub fn remove_mint_seed(ctx: Context<RemoveMintSeed>, index: u16) -> Result<()> {
+        mint_seed_pool::remove_mint_seed(ctx, index)
+    }
+
+    pub fn reserve_mint_seed(ctx: Context<ReserveMintSeed>, index: u16) -> Result<()> {
+        mint_seed_pool::reserve_mint_seed(ctx, index)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create(ctx: Context<Create>,
         name: String,
         symbol: String,
@@ -36,16 +78,178 @@ pub mod curve_launchpad {
         description: String,
         website: String,
         telegram: String,
-        twitter: String,) -> Result<()> {
-        create::create(ctx, name, symbol, uri,description,website,telegram,twitter)
+        twitter: String,
+        initial_buy_sol: Option<u64>,
+        min_tokens: u64,
+        vesting: Option<VestingParams>,
+        buy_fee_schedule: Option<FeeSchedule>,
+        sell_fee_schedule: Option<FeeSchedule>,
+        expires_at: Option<i64>,
+        launch_schedule: Option<LaunchSchedule>,
+        presale: Option<PresaleConfig>,
+        auction_end_time: Option<i64>,) -> Result<()> {
+        create::create(ctx, name, symbol, uri,description,website,telegram,twitter,initial_buy_sol,min_tokens,vesting,buy_fee_schedule,sell_fee_schedule,expires_at,launch_schedule,presale,auction_end_time)
+    }
+
+    pub fn commit_auction(ctx: Context<CommitAuction>, sol_amount: u64) -> Result<()> {
+        auction::commit_auction(ctx, sol_amount)
+    }
+
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        auction::settle_auction(ctx)
+    }
+
+    pub fn claim_auction(ctx: Context<ClaimAuction>) -> Result<()> {
+        auction::claim_auction(ctx)
+    }
+
+    pub fn place_limit_order(ctx: Context<PlaceLimitOrder>, is_buy: bool, amount: u64, limit_price: u64) -> Result<()> {
+        limit_order::place_limit_order(ctx, is_buy, amount, limit_price)
+    }
+
+    pub fn cancel_limit_order(ctx: Context<CancelLimitOrder>, order_id: u64) -> Result<()> {
+        limit_order::cancel_limit_order(ctx, order_id)
+    }
+
+    pub fn evict_limit_order(ctx: Context<EvictLimitOrder>, order_id: u64) -> Result<()> {
+        limit_order::evict_limit_order(ctx, order_id)
+    }
+
+    pub fn fill_orders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FillOrders<'info>>,
+        order_ids: Vec<u64>,
+    ) -> Result<()> {
+        limit_order::fill_orders(ctx, order_ids)
+    }
+
+    pub fn open_dca(
+        ctx: Context<OpenDca>,
+        total_sol: u64,
+        amount_per_cycle: u64,
+        interval_seconds: u64,
+        min_tokens_per_cycle: u64,
+    ) -> Result<()> {
+        dca::open_dca(ctx, total_sol, amount_per_cycle, interval_seconds, min_tokens_per_cycle)
+    }
+
+    pub fn execute_dca(ctx: Context<ExecuteDca>) -> Result<()> {
+        dca::execute_dca(ctx)
+    }
+
+    pub fn cancel_dca(ctx: Context<CancelDca>) -> Result<()> {
+        dca::cancel_dca(ctx)
+    }
+
+    pub fn fund_buyback(ctx: Context<FundBuyback>, amount: u64) -> Result<()> {
+        buyback::fund_buyback(ctx, amount)
+    }
+
+    pub fn buyback_and_burn(ctx: Context<BuybackAndBurn>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
+        buyback::buyback_and_burn(ctx, sol_amount, min_tokens_out)
+    }
+
+    pub fn withdraw_buyback(ctx: Context<WithdrawBuyback>) -> Result<()> {
+        buyback::withdraw_buyback(ctx)
+    }
+
+    pub fn presale_buy(
+        ctx: Context<PresaleBuy>,
+        token_amount: u64,
+        max_sol_cost: u64,
+        max_tokens: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        presale::presale_buy(ctx, token_amount, max_sol_cost, max_tokens, proof)
+    }
+
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, user: Pubkey) -> Result<()> {
+        allowlist::add_to_allowlist(ctx, user)
+    }
+
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>, user: Pubkey) -> Result<()> {
+        allowlist::remove_from_allowlist(ctx, user)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        vesting::claim_vested(ctx)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        creator_fees::claim_creator_fees(ctx)
+    }
+
+    pub fn set_creator_fee_recipient(ctx: Context<SetCreatorFeeRecipient>, recipient: Pubkey) -> Result<()> {
+        creator_fees::set_creator_fee_recipient(ctx, recipient)
+    }
+
+    pub fn renounce_creator_fees(ctx: Context<RenounceCreatorFees>) -> Result<()> {
+        creator_fees::renounce_creator_fees(ctx)
+    }
+
+    pub fn transfer_creator(ctx: Context<TransferCreator>, new_creator: Pubkey) -> Result<()> {
+        transfer_creator::transfer_creator(ctx, new_creator)
+    }
+
+    pub fn accept_creator(ctx: Context<AcceptCreator>) -> Result<()> {
+        transfer_creator::accept_creator(ctx)
+    }
+
+    pub fn update_profile(
+        ctx: Context<UpdateProfile>,
+        description: String,
+        website: String,
+        telegram: String,
+        twitter: String,
+        image_uri: String,
+        banner_uri: String,
+    ) -> Result<()> {
+        token_profile::update_profile(ctx, description, website, telegram, twitter, image_uri, banner_uri)
+    }
+
+    pub fn update_token_metadata(ctx: Context<UpdateTokenMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+        token_metadata::update_token_metadata(ctx, name, symbol, uri)
+    }
+
+    pub fn freeze_token_metadata(ctx: Context<FreezeTokenMetadata>) -> Result<()> {
+        token_metadata::freeze_token_metadata(ctx)
+    }
+
+    pub fn buy(
+        ctx: Context<Buy>,
+        token_amount: u64,
+        max_sol_cost: u64,
+        hash: String,
+        deadline: Option<i64>,
+        max_price: Option<u64>,
+    ) -> Result<()> {
+        buy::buy(ctx, token_amount, max_sol_cost, &hash, deadline, max_price)
+    }
+
+    pub fn sell(
+        ctx: Context<Sell>,
+        token_amount: u64,
+        min_sol_output: u64,
+        hash: String,
+        deadline: Option<i64>,
+        min_price: Option<u64>,
+    ) -> Result<()> {
+        sell::sell(ctx, token_amount, min_sol_output, &hash, deadline, min_price)
+    }
+
+    pub fn batch_trade<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchTrade<'info>>,
+        legs: Vec<TradeLeg>,
+        hash: String,
+    ) -> Result<()> {
+        batch_trade::batch_trade(ctx, legs, &hash)
     }
 
-    pub fn buy(ctx: Context<Buy>, token_amount: u64, max_sol_cost: u64 , hash : String) -> Result<()> {
-        buy::buy(ctx, token_amount, max_sol_cost,&hash)
+    pub fn redeem_expired(ctx: Context<RedeemExpired>, token_amount: u64) -> Result<()> {
+        expiry::redeem_expired(ctx, token_amount)
     }
 
-    pub fn sell(ctx: Context<Sell>, token_amount: u64, min_sol_output: u64 , hash : String) -> Result<()> {
-        sell::sell(ctx, token_amount, min_sol_output,&hash)
+    pub fn close_expired_curve(ctx: Context<CloseExpiredCurve>) -> Result<()> {
+        expiry::close_expired_curve(ctx)
     }
 
     pub fn protocol_fee_collect(ctx: Context<ProtocolFeeCollect>) -> Result<()>{
@@ -83,6 +287,7 @@ pub mod curve_launchpad {
         creator_fee_basis_points: u64,
         withdraw_authority: Pubkey,
         invite_fee_basis_points:u64,
+        max_creator_fee_basis_points: u64,
     ) -> Result<()> {
         set_params::set_fee_params(
             ctx,
@@ -91,9 +296,31 @@ pub mod curve_launchpad {
             creator_fee_basis_points,
             withdraw_authority,
             invite_fee_basis_points,
+            max_creator_fee_basis_points,
         )
     }
 
+    pub fn set_fee_tiers(ctx: Context<SetParams>, fee_tiers: Vec<FeeTier>) -> Result<()> {
+        set_params::set_fee_tiers(ctx, fee_tiers)
+    }
+
+    pub fn set_invite_levels(
+        ctx: Context<SetParams>,
+        invite_level_basis_points: Vec<u64>,
+        invite_requires_trade: bool,
+    ) -> Result<()> {
+        set_params::set_invite_levels(ctx, invite_level_basis_points, invite_requires_trade)
+    }
+
+    pub fn set_mint_vanity_rule(
+        ctx: Context<SetParams>,
+        pattern: String,
+        is_prefix: bool,
+        case_sensitive: bool,
+    ) -> Result<()> {
+        set_params::set_mint_vanity_rule(ctx, pattern, is_prefix, case_sensitive)
+    }
+
     pub fn set_protocol_fee_address(
         ctx: Context<SetParams>,
         protocol_token_alloc_recipient: Pubkey,
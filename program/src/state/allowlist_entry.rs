@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+//a user allowed to trade a curve during its allowlist phase
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+}
+
+impl AllowlistEntry {
+    pub const SEED_PREFIX: &'static [u8; 9] = b"allowlist";
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+//sol a wallet committed to a curve's opening auction
+#[account]
+#[derive(InitSpace)]
+pub struct AuctionCommitment {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub claimed: bool,
+}
+
+impl AuctionCommitment {
+    pub const SEED_PREFIX: &'static [u8; 18] = b"auction-commitment";
+}
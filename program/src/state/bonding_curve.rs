@@ -1,8 +1,63 @@
 use anchor_lang::prelude::*;
 use std::fmt;
 
+use crate::{calculate_decayed_basis_points, CurveLaunchpadError};
+
+// Creator fee in basis points, moving linearly from start to end over decay_seconds after create_time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct FeeSchedule {
+    pub start_basis_points: u64,
+    pub end_basis_points: u64,
+    pub decay_seconds: u64,
+}
+
+impl FeeSchedule {
+    pub fn flat(basis_points: u64) -> Self {
+        FeeSchedule {
+            start_basis_points: basis_points,
+            end_basis_points: basis_points,
+            decay_seconds: 0,
+        }
+    }
+
+    pub fn max_basis_points(&self) -> u64 {
+        self.start_basis_points.max(self.end_basis_points)
+    }
+}
+
+// Public trading opens at trading_start_time, allowlisted users may trade from allowlist_start_time (0 for no allowlist phase)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct LaunchSchedule {
+    pub trading_start_time: i64,
+    pub allowlist_start_time: i64,
+}
+
+// Presale for the wallets in merkle_root until end_time, price in lamports per token base unit scaled by PRICE_PRECISION.
+// At most allocation tokens are sold, the sol raised is held by the curve outside real_sol_reserves
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct PresaleConfig {
+    pub merkle_root: [u8; 32],
+    pub price: u64,
+    pub end_time: i64,
+    pub allocation: u64,
+    pub sold_tokens: u64,
+    pub sol_raised: u64,
+}
+
+// Opening auction, commitments are accepted until commit_end_time and settled as a single buy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct AuctionState {
+    pub commit_end_time: i64,
+    pub total_committed: u64,
+    pub total_tokens: u64,
+    pub refund_sol: u64,
+    pub settled: bool,
+    pub claimed_tokens: u64,
+    pub claimed_refund_sol: u64,
+}
+
 #[account]
-#[derive(InitSpace)]
+#[derive(Default, InitSpace)]
 pub struct BondingCurve {
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
@@ -18,10 +73,213 @@ pub struct BondingCurve {
     pub mint: Pubkey,
     pub create_time: u64,
     pub update_time: u64,
+
+    pub buy_fee_schedule: FeeSchedule,
+    pub sell_fee_schedule: FeeSchedule,
+
+    //creator fees earned by this curve, held in the fee account until claimed
+    pub creator_fee_recipient: Pubkey,
+    pub creator_fee_claimable: u64,
+    pub creator_fee_claimed: u64,
+    pub creator_fee_renounced: bool,
+
+    //trading stops at this time unless the curve completed before, 0 means never
+    pub expires_at: i64,
+    //set by close_expired_curve, the curve holds no more sol to redeem
+    pub expired_closed: bool,
+
+    pub launch_schedule: LaunchSchedule,
+    pub presale: PresaleConfig,
+    pub auction: AuctionState,
+
+    //proposed new creator, set by transfer_creator until accepted
+    pub pending_creator: Pubkey,
 }
 
 impl BondingCurve {
     pub const SEED_PREFIX: &'static [u8; 13] = b"bonding-curve";
+
+    pub fn check_trading_open(&self, now: i64, is_allowlisted: bool) -> Result<()> {
+        //an opening auction must be settled before anyone trades
+        require!(
+            self.auction.commit_end_time == 0 || self.auction.settled,
+            CurveLaunchpadError::AuctionNotSettled
+        );
+
+        let schedule = &self.launch_schedule;
+        if now >= schedule.trading_start_time {
+            return Ok(());
+        }
+
+        let allowlist_phase = schedule.allowlist_start_time > 0 && now >= schedule.allowlist_start_time;
+        require!(allowlist_phase, CurveLaunchpadError::TradingNotStarted);
+        require!(is_allowlisted, CurveLaunchpadError::NotAllowlisted);
+
+        Ok(())
+    }
+
+    //a sold out curve is complete, also when an older trade path did not set the flag
+    pub fn is_complete(&self) -> bool {
+        self.complete || self.real_token_reserves == 0
+    }
+
+    pub fn is_presale_active(&self, now: i64) -> bool {
+        self.presale.merkle_root != [0; 32] && now < self.presale.end_time
+    }
+
+    //the presale sol is held outside real_sol_reserves, so it never moves the price
+    pub fn record_presale_buy(&mut self, token_amount: u64, sol_amount: u64) -> Result<()> {
+        self.real_token_reserves = self
+            .real_token_reserves
+            .checked_sub(token_amount)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+        self.presale.sold_tokens = self
+            .presale
+            .sold_tokens
+            .checked_add(token_amount)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+        self.presale.sol_raised = self
+            .presale
+            .sol_raised
+            .checked_add(sol_amount)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    //sol that migrates to the pool on withdraw, the curve reserves and the presale proceeds
+    pub fn withdrawable_sol(&self) -> Result<u64> {
+        self.real_sol_reserves
+            .checked_add(self.presale.sol_raised)
+            .ok_or(error!(CurveLaunchpadError::MathOverflow))
+    }
+
+    pub fn is_auction_open(&self, now: i64) -> bool {
+        self.auction.commit_end_time > 0 && now < self.auction.commit_end_time && !self.is_expired(now)
+    }
+
+    //the auction buys ahead of the public start, so a launch with an allowlist phase keeps it to allowlisted users
+    pub fn check_auction_commit(&self, now: i64, is_allowlisted: bool) -> Result<()> {
+        require!(!self.is_expired(now), CurveLaunchpadError::BondingCurveExpired);
+        require!(self.is_auction_open(now), CurveLaunchpadError::AuctionNotActive);
+        require!(
+            self.launch_schedule.allowlist_start_time == 0 || is_allowlisted,
+            CurveLaunchpadError::NotAllowlisted
+        );
+
+        Ok(())
+    }
+
+    //settled auction tokens and refunds still held by the curve for their participants
+    pub fn unclaimed_auction_tokens(&self) -> u64 {
+        self.auction.total_tokens - self.auction.claimed_tokens
+    }
+
+    pub fn unclaimed_auction_refund(&self) -> u64 {
+        self.auction.refund_sol - self.auction.claimed_refund_sol
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at > 0 && now >= self.expires_at && !self.is_complete()
+    }
+
+    //buybacks end, and the treasury can be withdrawn, once the curve sold out or expired
+    pub fn is_buyback_over(&self, now: i64) -> bool {
+        self.is_complete() || self.is_expired(now)
+    }
+
+    //tokens burned by a buyback leave the supply
+    pub fn burn_supply(&mut self, token_amount: u64) -> Result<()> {
+        self.token_total_supply = self
+            .token_total_supply
+            .checked_sub(token_amount)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    //pays out the share of the curve and presale sol backing token_amount of the circulating supply,
+    //returning the (curve, presale) sol amounts
+    pub fn redeem_expired(&mut self, token_amount: u64, circulating_supply: u64) -> Result<(u64, u64)> {
+        require!(!self.expired_closed, CurveLaunchpadError::ExpiredCurveClosed);
+
+        let curve_sol_amount = (self.real_sol_reserves as u128 * token_amount as u128 / circulating_supply as u128) as u64;
+        let presale_sol_amount =
+            (self.presale.sol_raised as u128 * token_amount as u128 / circulating_supply as u128) as u64;
+
+        self.real_sol_reserves = self
+            .real_sol_reserves
+            .checked_sub(curve_sol_amount)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+        self.presale.sol_raised = self
+            .presale
+            .sol_raised
+            .checked_sub(presale_sol_amount)
+            .ok_or(CurveLaunchpadError::MathOverflow)?;
+
+        Ok((curve_sol_amount, presale_sol_amount))
+    }
+
+    //completes a transfer_creator, returning the old creator. the fee entitlement,
+    //including unclaimed fees, follows the creator role
+    pub fn accept_creator(&mut self, new_creator: Pubkey) -> Result<Pubkey> {
+        require!(
+            self.pending_creator != Pubkey::default() && self.pending_creator == new_creator,
+            CurveLaunchpadError::InvalidPendingCreator
+        );
+
+        let old_creator = self.creator;
+        self.creator = new_creator;
+        self.pending_creator = Pubkey::default();
+        if !self.creator_fee_renounced {
+            self.creator_fee_recipient = new_creator;
+        }
+
+        Ok(old_creator)
+    }
+
+    //the creator or the current fee recipient claims everything accrued so far
+    pub fn claim_creator_fees(&mut self, claimer: Pubkey) -> Result<u64> {
+        require!(
+            claimer == self.creator || claimer == self.creator_fee_recipient,
+            CurveLaunchpadError::InvalidClaimer
+        );
+
+        let claim_amount = self.creator_fee_claimable;
+        require!(claim_amount > 0, CurveLaunchpadError::NotClaimableFee);
+
+        self.creator_fee_claimable = 0;
+        self.creator_fee_claimed += claim_amount;
+
+        Ok(claim_amount)
+    }
+
+    //returns the unclaimed fees, which move to the protocol with every later creator fee
+    pub fn renounce_creator_fees(&mut self) -> Result<u64> {
+        require!(!self.creator_fee_renounced, CurveLaunchpadError::CreatorFeesRenounced);
+
+        let unclaimed = self.creator_fee_claimable;
+        self.creator_fee_claimable = 0;
+        self.creator_fee_renounced = true;
+
+        Ok(unclaimed)
+    }
+
+    pub fn creator_fee_basis_points(&self, is_buy: bool, now: i64) -> u64 {
+        let schedule = if is_buy {
+            &self.buy_fee_schedule
+        } else {
+            &self.sell_fee_schedule
+        };
+        let elapsed = (now as u64).saturating_sub(self.create_time);
+
+        calculate_decayed_basis_points(
+            schedule.start_basis_points,
+            schedule.end_basis_points,
+            schedule.decay_seconds,
+            elapsed,
+        )
+    }
 }
 
 impl fmt::Display for BondingCurve {
@@ -38,3 +296,249 @@ impl fmt::Display for BondingCurve {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creator_fee_basis_points() {
+        let bonding_curve = BondingCurve {
+            create_time: 100,
+            buy_fee_schedule: FeeSchedule::flat(35),
+            sell_fee_schedule: FeeSchedule {
+                start_basis_points: 1000,
+                end_basis_points: 35,
+                decay_seconds: 100,
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(bonding_curve.creator_fee_basis_points(true, 100), 35);
+        assert_eq!(bonding_curve.creator_fee_basis_points(true, 1000), 35);
+        //the sell tax decays from the creation time
+        assert_eq!(bonding_curve.creator_fee_basis_points(false, 100), 1000);
+        assert_eq!(bonding_curve.creator_fee_basis_points(false, 150), 518);
+        assert_eq!(bonding_curve.creator_fee_basis_points(false, 200), 35);
+        assert_eq!(bonding_curve.sell_fee_schedule.max_basis_points(), 1000);
+    }
+
+    #[test]
+    fn test_check_auction_commit() {
+        let mut bonding_curve = BondingCurve {
+            real_token_reserves: 100,
+            expires_at: 200,
+            ..Default::default()
+        };
+        bonding_curve.auction.commit_end_time = 100;
+
+        assert!(bonding_curve.check_auction_commit(99, false).is_ok());
+        assert_eq!(
+            bonding_curve.check_auction_commit(100, false).unwrap_err(),
+            CurveLaunchpadError::AuctionNotActive.into()
+        );
+
+        bonding_curve.launch_schedule.allowlist_start_time = 100;
+        assert_eq!(
+            bonding_curve.check_auction_commit(99, false).unwrap_err(),
+            CurveLaunchpadError::NotAllowlisted.into()
+        );
+        assert!(bonding_curve.check_auction_commit(99, true).is_ok());
+
+        bonding_curve.expires_at = 50;
+        assert_eq!(
+            bonding_curve.check_auction_commit(99, true).unwrap_err(),
+            CurveLaunchpadError::BondingCurveExpired.into()
+        );
+    }
+
+    #[test]
+    fn test_record_presale_buy() {
+        let mut bonding_curve = BondingCurve {
+            real_token_reserves: 1_000,
+            real_sol_reserves: 500,
+            ..Default::default()
+        };
+
+        bonding_curve.record_presale_buy(100, 40).unwrap();
+        bonding_curve.record_presale_buy(50, 20).unwrap();
+        assert_eq!(bonding_curve.real_token_reserves, 850);
+        assert_eq!(bonding_curve.presale.sold_tokens, 150);
+        assert_eq!(bonding_curve.presale.sol_raised, 60);
+        //the curve reserves are untouched but the presale sol still migrates
+        assert_eq!(bonding_curve.real_sol_reserves, 500);
+        assert_eq!(bonding_curve.withdrawable_sol().unwrap(), 560);
+
+        bonding_curve.presale.sol_raised = u64::MAX;
+        assert_eq!(
+            bonding_curve.record_presale_buy(1, 1).unwrap_err(),
+            CurveLaunchpadError::MathOverflow.into()
+        );
+        assert!(bonding_curve.withdrawable_sol().is_err());
+    }
+
+    #[test]
+    fn test_check_trading_open() {
+        let mut bonding_curve = BondingCurve {
+            launch_schedule: LaunchSchedule {
+                trading_start_time: 100,
+                allowlist_start_time: 0,
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            bonding_curve.check_trading_open(99, true).unwrap_err(),
+            CurveLaunchpadError::TradingNotStarted.into()
+        );
+        assert!(bonding_curve.check_trading_open(100, false).is_ok());
+
+        bonding_curve.launch_schedule.allowlist_start_time = 50;
+        assert_eq!(
+            bonding_curve.check_trading_open(49, true).unwrap_err(),
+            CurveLaunchpadError::TradingNotStarted.into()
+        );
+        assert_eq!(
+            bonding_curve.check_trading_open(50, false).unwrap_err(),
+            CurveLaunchpadError::NotAllowlisted.into()
+        );
+        assert!(bonding_curve.check_trading_open(50, true).is_ok());
+
+        //an unsettled auction holds trading even after the start
+        bonding_curve.auction.commit_end_time = 80;
+        assert_eq!(
+            bonding_curve.check_trading_open(100, true).unwrap_err(),
+            CurveLaunchpadError::AuctionNotSettled.into()
+        );
+    }
+
+    #[test]
+    fn test_buyback() {
+        let mut bonding_curve = BondingCurve {
+            real_token_reserves: 100,
+            token_total_supply: 1_000,
+            expires_at: 50,
+            ..Default::default()
+        };
+        assert!(!bonding_curve.is_buyback_over(49));
+        assert!(bonding_curve.is_buyback_over(50));
+
+        //sold out without the complete flag
+        bonding_curve.real_token_reserves = 0;
+        assert!(!bonding_curve.complete);
+        assert!(bonding_curve.is_buyback_over(0));
+
+        bonding_curve.burn_supply(400).unwrap();
+        assert_eq!(bonding_curve.token_total_supply, 600);
+        assert_eq!(
+            bonding_curve.burn_supply(601).unwrap_err(),
+            CurveLaunchpadError::MathOverflow.into()
+        );
+    }
+
+    #[test]
+    fn test_redeem_expired() {
+        let mut bonding_curve = BondingCurve {
+            real_token_reserves: 100,
+            real_sol_reserves: 1_000,
+            expires_at: 50,
+            ..Default::default()
+        };
+        bonding_curve.presale.sol_raised = 300;
+        assert!(!bonding_curve.is_expired(49));
+        assert!(bonding_curve.is_expired(50));
+
+        //a quarter of the circulating supply takes a quarter of both pots
+        assert_eq!(bonding_curve.redeem_expired(25, 100).unwrap(), (250, 75));
+        assert_eq!(bonding_curve.real_sol_reserves, 750);
+        assert_eq!(bonding_curve.presale.sol_raised, 225);
+        assert_eq!(bonding_curve.redeem_expired(75, 75).unwrap(), (750, 225));
+        assert_eq!(bonding_curve.real_sol_reserves, 0);
+
+        bonding_curve.expired_closed = true;
+        assert_eq!(
+            bonding_curve.redeem_expired(1, 1).unwrap_err(),
+            CurveLaunchpadError::ExpiredCurveClosed.into()
+        );
+
+        //a sold out curve never expires
+        bonding_curve.real_token_reserves = 0;
+        assert!(!bonding_curve.is_expired(50));
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let mut bonding_curve = BondingCurve {
+            real_token_reserves: 100,
+            ..Default::default()
+        };
+        assert!(!bonding_curve.is_complete());
+
+        bonding_curve.real_token_reserves = 0;
+        assert!(bonding_curve.is_complete());
+
+        bonding_curve.real_token_reserves = 100;
+        bonding_curve.complete = true;
+        assert!(bonding_curve.is_complete());
+    }
+
+    #[test]
+    fn test_accept_creator() {
+        let creator = Pubkey::new_unique();
+        let new_creator = Pubkey::new_unique();
+        let mut bonding_curve = BondingCurve {
+            creator,
+            creator_fee_recipient: creator,
+            creator_fee_claimable: 100,
+            ..Default::default()
+        };
+
+        //nothing pending
+        assert_eq!(
+            bonding_curve.accept_creator(new_creator).unwrap_err(),
+            CurveLaunchpadError::InvalidPendingCreator.into()
+        );
+
+        bonding_curve.pending_creator = new_creator;
+        assert_eq!(
+            bonding_curve.accept_creator(Pubkey::new_unique()).unwrap_err(),
+            CurveLaunchpadError::InvalidPendingCreator.into()
+        );
+        assert_eq!(bonding_curve.accept_creator(new_creator).unwrap(), creator);
+        assert_eq!(bonding_curve.creator, new_creator);
+        assert_eq!(bonding_curve.pending_creator, Pubkey::default());
+        //the unclaimed fees move with the role
+        assert_eq!(bonding_curve.creator_fee_recipient, new_creator);
+        assert_eq!(bonding_curve.creator_fee_claimable, 100);
+    }
+
+    #[test]
+    fn test_claim_and_renounce_creator_fees() {
+        let creator = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let mut bonding_curve = BondingCurve {
+            creator,
+            creator_fee_recipient: recipient,
+            creator_fee_claimable: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            bonding_curve.claim_creator_fees(Pubkey::new_unique()).unwrap_err(),
+            CurveLaunchpadError::InvalidClaimer.into()
+        );
+        assert_eq!(bonding_curve.claim_creator_fees(recipient).unwrap(), 100);
+        assert_eq!(
+            bonding_curve.claim_creator_fees(creator).unwrap_err(),
+            CurveLaunchpadError::NotClaimableFee.into()
+        );
+        assert_eq!(bonding_curve.creator_fee_claimed, 100);
+
+        bonding_curve.creator_fee_claimable = 40;
+        assert_eq!(bonding_curve.renounce_creator_fees().unwrap(), 40);
+        assert_eq!(bonding_curve.creator_fee_claimable, 0);
+        assert_eq!(
+            bonding_curve.renounce_creator_fees().unwrap_err(),
+            CurveLaunchpadError::CreatorFeesRenounced.into()
+        );
+    }
+}
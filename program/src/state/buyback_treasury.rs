@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+//sol held above rent is spent by buyback_and_burn, creator fees reach it by setting
+//the curve's creator_fee_recipient to this account
+#[account]
+#[derive(InitSpace)]
+pub struct BuybackTreasury {
+    pub mint: Pubkey,
+    pub total_spent: u64,
+    pub total_burned: u64,
+}
+
+impl BuybackTreasury {
+    pub const SEED_PREFIX: &'static [u8; 16] = b"buyback-treasury";
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::calculate_vested_amount;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorVesting {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start_time: u64,
+    pub cliff_time: u64,
+    pub end_time: u64,
+}
+
+impl CreatorVesting {
+    pub const SEED_PREFIX: &'static [u8; 15] = b"creator-vesting";
+    pub const VAULT_SEED_PREFIX: &'static [u8; 21] = b"creator-vesting-vault";
+
+    pub fn claimable_amount(&self, now: u64) -> u64 {
+        let vested = calculate_vested_amount(
+            self.total_amount,
+            self.start_time,
+            self.cliff_time,
+            self.end_time,
+            now,
+        );
+        vested.saturating_sub(self.claimed_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claimable_amount() {
+        let mut creator_vesting = CreatorVesting {
+            creator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            total_amount: 1000,
+            claimed_amount: 0,
+            start_time: 100,
+            cliff_time: 150,
+            end_time: 200,
+        };
+
+        assert_eq!(creator_vesting.claimable_amount(120), 0);
+        assert_eq!(creator_vesting.claimable_amount(175), 750);
+
+        //claims are deducted from the vested amount
+        creator_vesting.claimed_amount = 750;
+        assert_eq!(creator_vesting.claimable_amount(175), 0);
+        assert_eq!(creator_vesting.claimable_amount(500), 250);
+    }
+}
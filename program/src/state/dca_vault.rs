@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+//sol escrowed in this account is spent on the mint in fixed cycles by execute_dca
+#[account]
+#[derive(InitSpace)]
+pub struct DcaVault {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub interval_seconds: u64,
+    pub amount_per_cycle: u64,
+    pub min_tokens_per_cycle: u64,
+    pub remaining_sol: u64,
+    pub next_execution_time: i64,
+    pub cycles_executed: u64,
+}
+
+impl DcaVault {
+    pub const SEED_PREFIX: &'static [u8; 9] = b"dca-vault";
+}
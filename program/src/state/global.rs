@@ -1,9 +1,16 @@
 use anchor_lang::prelude::*;
 
+use crate::{encode_base58_address, matches_vanity, MAX_FEE_TIERS, MAX_INVITE_LEVELS, MAX_MINT_VANITY_LEN};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct FeeTier {
+    pub min_volume: u64,
+    pub discount_basis_points: u64,
+}
 
 
 #[account]
-#[derive(InitSpace)]
+#[derive(Default, InitSpace)]
 pub struct Global {
     pub authority: Pubkey,
     pub initialized: bool,
@@ -19,8 +26,114 @@ pub struct Global {
     pub protocol_token_alloc_recipient:Pubkey,
 
     pub invite_fee_basis_points: u64,
+
+    pub max_creator_fee_basis_points: u64,
+
+    pub fee_tiers: [FeeTier; MAX_FEE_TIERS],
+
+    //invite fee paid to each referral level, summing up to invite_fee_basis_points
+    pub invite_level_basis_points: [u64; MAX_INVITE_LEVELS],
+    //referrers only earn invite fees once they have traded themselves
+    pub invite_requires_trade: bool,
+
+    //base58 prefix or suffix every mint must carry, an empty pattern disables the rule
+    pub mint_vanity_pattern: [u8; MAX_MINT_VANITY_LEN],
+    pub mint_vanity_len: u8,
+    pub mint_vanity_is_prefix: bool,
+    pub mint_vanity_case_sensitive: bool,
+
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_token_supply: u64,
 }
 
 impl Global {
    pub const SEED_PREFIX: &'static [u8; 6] = b"CONFIG";
-}
\ No newline at end of file
+
+   // Returns the 1-based tier reached by the volume (0 for none) and the discounted protocol fee
+   pub fn discounted_fee_basis_points(&self, volume: u64) -> (u8, u64) {
+        let mut tier = 0;
+        for (i, fee_tier) in self.fee_tiers.iter().enumerate() {
+            if fee_tier.min_volume > 0 && volume >= fee_tier.min_volume {
+                tier = i + 1;
+            }
+        }
+        if tier == 0 {
+            return (0, self.fee_basis_points);
+        }
+
+        let discount = self.fee_tiers[tier - 1].discount_basis_points;
+        (tier as u8, self.fee_basis_points * (10000 - discount) / 10000)
+   }
+
+   // Protocol, creator and invite fees together must stay below the traded amount
+   pub fn fee_basis_points_in_bounds(&self) -> bool {
+        self.creator_fee_basis_points <= self.max_creator_fee_basis_points
+            && self.fee_basis_points + self.max_creator_fee_basis_points + self.invite_fee_basis_points < 10000
+   }
+
+   pub fn mint_matches_vanity(&self, mint: &Pubkey) -> bool {
+        if self.mint_vanity_len == 0 {
+            return true;
+        }
+
+        let mut address = [0u8; 44];
+        let len = encode_base58_address(&mint.to_bytes(), &mut address);
+        matches_vanity(
+            &address[..len],
+            &self.mint_vanity_pattern[..self.mint_vanity_len as usize],
+            self.mint_vanity_is_prefix,
+            self.mint_vanity_case_sensitive,
+        )
+   }
+
+   // Mints must end in "meme", in any case
+   pub fn set_default_mint_vanity(&mut self) {
+        self.mint_vanity_pattern = [0; MAX_MINT_VANITY_LEN];
+        self.mint_vanity_pattern[..4].copy_from_slice(b"meme");
+        self.mint_vanity_len = 4;
+        self.mint_vanity_is_prefix = false;
+        self.mint_vanity_case_sensitive = false;
+   }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discounted_fee_basis_points() {
+        let mut global = Global {
+            fee_basis_points: 100,
+            ..Default::default()
+        };
+        //no tiers configured
+        assert_eq!(global.discounted_fee_basis_points(1_000_000), (0, 100));
+
+        global.fee_tiers[0] = FeeTier { min_volume: 1_000, discount_basis_points: 1000 };
+        global.fee_tiers[1] = FeeTier { min_volume: 10_000, discount_basis_points: 5000 };
+        assert_eq!(global.discounted_fee_basis_points(999), (0, 100));
+        assert_eq!(global.discounted_fee_basis_points(1_000), (1, 90));
+        assert_eq!(global.discounted_fee_basis_points(50_000), (2, 50));
+    }
+
+    #[test]
+    fn test_mint_matches_vanity() {
+        let mint = Pubkey::new_from_array([1; 32]);
+        let mut address = [0u8; 44];
+        let len = encode_base58_address(&mint.to_bytes(), &mut address);
+
+        let mut global = Global::default();
+        //a zero length disables the rule
+        assert!(global.mint_matches_vanity(&mint));
+
+        global.set_default_mint_vanity();
+        assert_eq!(&global.mint_vanity_pattern[..4], b"meme");
+        assert!(!global.mint_matches_vanity(&mint));
+
+        global.mint_vanity_pattern[..3].copy_from_slice(&address[len - 3..len]);
+        global.mint_vanity_len = 3;
+        assert!(global.mint_matches_vanity(&mint));
+        global.mint_vanity_is_prefix = true;
+        assert_eq!(global.mint_matches_vanity(&mint), address[..3] == address[len - 3..len]);
+    }
+}
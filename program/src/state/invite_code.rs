@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_INVITE_CODE_LEN;
+
+#[account]
+#[derive(InitSpace)]
+pub struct InviteCode {
+    pub owner: Pubkey,
+    #[max_len(MAX_INVITE_CODE_LEN)]
+    pub code: String,
+    pub create_time: u64,
+}
+
+impl InviteCode {
+    pub const SEED_PREFIX: &'static [u8; 11] = b"invite-code";
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::MINT_SEED_POOL_CAPACITY;
+
+//pre-ground seeds whose mint PDA already satisfies the vanity rule
+#[account]
+#[derive(InitSpace)]
+pub struct MintSeedPool {
+    pub seeds: [u64; MINT_SEED_POOL_CAPACITY],
+    pub len: u16,
+}
+
+impl MintSeedPool {
+    pub const SEED_PREFIX: &'static [u8; 14] = b"mint-seed-pool";
+    //pool mints live apart from init_create_account mints, so nobody can claim a pooled seed there
+    pub const MINT_SEED_PREFIX: &'static [u8; 9] = b"pool-mint";
+
+    pub fn mint_address(seed: u64) -> Pubkey {
+        Pubkey::find_program_address(&[Self::MINT_SEED_PREFIX, &seed.to_le_bytes()], &crate::ID).0
+    }
+
+    //out of range indexes resolve to 0 and are rejected by the handler
+    pub fn seed_at(&self, index: u16) -> u64 {
+        if index < self.len {
+            self.seeds[index as usize]
+        } else {
+            0
+        }
+    }
+
+    pub fn push(&mut self, seed: u64) -> bool {
+        if self.len as usize >= MINT_SEED_POOL_CAPACITY {
+            return false;
+        }
+        self.seeds[self.len as usize] = seed;
+        self.len += 1;
+        true
+    }
+
+    pub fn swap_remove(&mut self, index: u16) -> u64 {
+        let seed = self.seeds[index as usize];
+        self.len -= 1;
+        self.seeds[index as usize] = self.seeds[self.len as usize];
+        self.seeds[self.len as usize] = 0;
+        seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_seed_pool() {
+        let mut pool = MintSeedPool {
+            seeds: [0; MINT_SEED_POOL_CAPACITY],
+            len: 0,
+        };
+
+        assert!(pool.push(7));
+        assert!(pool.push(8));
+        assert!(pool.push(9));
+        assert_eq!(pool.seed_at(1), 8);
+        //out of range indexes resolve to 0
+        assert_eq!(pool.seed_at(3), 0);
+
+        //the last seed fills the removed slot
+        assert_eq!(pool.swap_remove(0), 7);
+        assert_eq!(pool.len, 2);
+        assert_eq!(pool.seed_at(0), 9);
+        assert_eq!(pool.seed_at(1), 8);
+        assert_eq!(pool.seeds[2], 0);
+
+        for seed in 0..(MINT_SEED_POOL_CAPACITY - 2) as u64 {
+            assert!(pool.push(seed + 100));
+        }
+        assert!(!pool.push(1));
+
+        //pool mints do not collide with one another
+        assert_ne!(MintSeedPool::mint_address(7), MintSeedPool::mint_address(8));
+    }
+}
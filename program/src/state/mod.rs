@@ -1,8 +1,30 @@
 pub mod global;
 pub mod bonding_curve;
 pub mod fee_account;
+pub mod creator_vesting;
+pub mod invite_code;
+pub mod token_profile;
+pub mod mint_seed_pool;
+pub mod allowlist_entry;
+pub mod presale_claim;
+pub mod auction_commitment;
+pub mod order_book;
+pub mod dca_vault;
+pub mod buyback_treasury;
+pub mod user_invite_stats;
 
 pub use global::*;
 pub use bonding_curve::*;
 pub use fee_account::*;
+pub use creator_vesting::*;
+pub use invite_code::*;
+pub use token_profile::*;
+pub use mint_seed_pool::*;
+pub use allowlist_entry::*;
+pub use presale_claim::*;
+pub use auction_commitment::*;
+pub use order_book::*;
+pub use dca_vault::*;
+pub use buyback_treasury::*;
+pub use user_invite_stats::*;
 
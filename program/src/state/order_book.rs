@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::{LIMIT_ORDER_MIN_DOUBLING_ORDERS, LIMIT_ORDER_TTL_SECONDS, MAX_LIMIT_ORDERS};
+
+// A resting order, amount is escrowed sol (fees included) for a buy and escrowed tokens for a sell.
+// limit_price is in lamports per token base unit scaled by PRICE_PRECISION
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct LimitOrder {
+    pub id: u64,
+    pub owner: Pubkey,
+    pub is_buy: bool,
+    pub amount: u64,
+    pub limit_price: u64,
+    pub create_time: i64,
+}
+
+impl LimitOrder {
+    pub fn is_stale(&self, now: i64) -> bool {
+        now >= self.create_time + LIMIT_ORDER_TTL_SECONDS
+    }
+}
+
+//a slot with a default owner is free
+#[account]
+#[derive(InitSpace)]
+pub struct OrderBook {
+    pub mint: Pubkey,
+    pub next_order_id: u64,
+    pub orders: [LimitOrder; MAX_LIMIT_ORDERS],
+}
+
+impl OrderBook {
+    pub const SEED_PREFIX: &'static [u8; 10] = b"order-book";
+    pub const VAULT_SEED_PREFIX: &'static [u8; 16] = b"order-book-vault";
+
+    pub fn insert(&mut self, order: LimitOrder) -> Option<u64> {
+        let slot = self.orders.iter_mut().find(|o| o.owner == Pubkey::default())?;
+        *slot = LimitOrder {
+            id: self.next_order_id,
+            ..order
+        };
+        self.next_order_id += 1;
+        Some(slot.id)
+    }
+
+    pub fn find(&self, id: u64) -> Option<usize> {
+        self.orders
+            .iter()
+            .position(|o| o.owner != Pubkey::default() && o.id == id)
+    }
+
+    pub fn count_for(&self, owner: &Pubkey) -> usize {
+        self.orders.iter().filter(|o| o.owner == *owner).count()
+    }
+
+    //the minimum escrow doubles with every LIMIT_ORDER_MIN_DOUBLING_ORDERS resting orders, so holding
+    //the whole book until the ttl ties up far more than a few small orders would
+    pub fn min_order_amount(&self, base_amount: u64) -> u64 {
+        let resting = self.orders.iter().filter(|o| o.owner != Pubkey::default()).count();
+        base_amount.saturating_mul(1 << (resting / LIMIT_ORDER_MIN_DOUBLING_ORDERS))
+    }
+
+    pub fn remove(&mut self, index: usize) -> LimitOrder {
+        std::mem::take(&mut self.orders[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_order_amount() {
+        let mut order_book = OrderBook {
+            mint: Pubkey::default(),
+            next_order_id: 0,
+            orders: [LimitOrder::default(); MAX_LIMIT_ORDERS],
+        };
+        assert_eq!(order_book.min_order_amount(100), 100);
+
+        for i in 0..LIMIT_ORDER_MIN_DOUBLING_ORDERS {
+            let order = LimitOrder {
+                owner: Pubkey::new_unique(),
+                ..Default::default()
+            };
+            assert_eq!(order_book.insert(order), Some(i as u64));
+        }
+        assert_eq!(order_book.min_order_amount(100), 200);
+
+        //a freed slot lowers the minimum again
+        order_book.remove(0);
+        assert_eq!(order_book.find(0), None);
+        assert_eq!(order_book.min_order_amount(100), 100);
+
+        let mut resting = LIMIT_ORDER_MIN_DOUBLING_ORDERS - 1;
+        while resting < MAX_LIMIT_ORDERS {
+            let order = LimitOrder {
+                owner: Pubkey::new_unique(),
+                ..Default::default()
+            };
+            assert!(order_book.insert(order).is_some());
+            resting += 1;
+        }
+        assert_eq!(order_book.insert(LimitOrder::default()), None);
+        let doublings = MAX_LIMIT_ORDERS / LIMIT_ORDER_MIN_DOUBLING_ORDERS;
+        assert_eq!(order_book.min_order_amount(100), 100 << doublings);
+    }
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+//tokens a wallet has bought in a curve's presale, bounded by its merkle allocation
+#[account]
+#[derive(InitSpace)]
+pub struct PresaleClaim {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub claimed_tokens: u64,
+}
+
+impl PresaleClaim {
+    pub const SEED_PREFIX: &'static [u8; 13] = b"presale-claim";
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{CurveLaunchpadError, MAX_DESCRIPTION_LEN, MAX_PROFILE_URI_LEN, MAX_SOCIAL_LINK_LEN};
+
+#[account]
+#[derive(Default, InitSpace)]
+pub struct TokenProfile {
+    pub mint: Pubkey,
+    #[max_len(MAX_DESCRIPTION_LEN)]
+    pub description: String,
+    #[max_len(MAX_SOCIAL_LINK_LEN)]
+    pub website: String,
+    #[max_len(MAX_SOCIAL_LINK_LEN)]
+    pub telegram: String,
+    #[max_len(MAX_SOCIAL_LINK_LEN)]
+    pub twitter: String,
+    #[max_len(MAX_PROFILE_URI_LEN)]
+    pub image_uri: String,
+    #[max_len(MAX_PROFILE_URI_LEN)]
+    pub banner_uri: String,
+    pub update_time: u64,
+}
+
+impl TokenProfile {
+    pub const SEED_PREFIX: &'static [u8; 13] = b"token-profile";
+
+    pub fn set_fields(
+        &mut self,
+        description: String,
+        website: String,
+        telegram: String,
+        twitter: String,
+        image_uri: String,
+        banner_uri: String,
+    ) -> Result<()> {
+        require!(
+            description.len() <= MAX_DESCRIPTION_LEN
+                && website.len() <= MAX_SOCIAL_LINK_LEN
+                && telegram.len() <= MAX_SOCIAL_LINK_LEN
+                && twitter.len() <= MAX_SOCIAL_LINK_LEN
+                && image_uri.len() <= MAX_PROFILE_URI_LEN
+                && banner_uri.len() <= MAX_PROFILE_URI_LEN,
+            CurveLaunchpadError::ProfileFieldTooLong
+        );
+
+        self.description = description;
+        self.website = website;
+        self.telegram = telegram;
+        self.twitter = twitter;
+        self.image_uri = image_uri;
+        self.banner_uri = banner_uri;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_fields() {
+        let mut token_profile = TokenProfile::default();
+
+        token_profile
+            .set_fields(
+                "a meme".to_string(),
+                "https://meme.xyz".to_string(),
+                "meme_chat".to_string(),
+                "meme".to_string(),
+                "ipfs://image".to_string(),
+                String::new(),
+            )
+            .unwrap();
+        assert_eq!(token_profile.website, "https://meme.xyz");
+        assert_eq!(token_profile.image_uri, "ipfs://image");
+        assert!(token_profile.banner_uri.is_empty());
+
+        //an overlong field leaves the profile unchanged
+        let result = token_profile.set_fields(
+            "a".repeat(MAX_DESCRIPTION_LEN + 1),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        assert_eq!(result.unwrap_err(), CurveLaunchpadError::ProfileFieldTooLong.into());
+        assert_eq!(token_profile.description, "a meme");
+    }
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::{record_volume, rolling_volume, CurveLaunchpadError, MAX_INVITE_LEVELS, SECONDS_PER_DAY, VOLUME_WINDOW_DAYS};
+
 #[account]
-#[derive(InitSpace)]
+#[derive(Default, InitSpace)]
 pub struct UserInviteStats {
 
     pub key: Pubkey,
@@ -12,10 +14,97 @@ pub struct UserInviteStats {
     pub profit_claimable: u64,
     pub profit_claim_accumulated: u64,
     pub is_init: bool,
+
+    //invite earnings split by referral level, index 0 is from direct children
+    pub profit_from_level: [u64; MAX_INVITE_LEVELS],
+
+    //daily SOL volume buckets for the rolling fee tier window
+    pub volume_day: u64,
+    pub volume_buckets: [u64; VOLUME_WINDOW_DAYS],
+
+    pub has_traded: bool,
+    pub bind_time: u64,
+    pub rebound: bool,
+
+    //may claim profits on behalf of the owner
+    pub claim_delegate: Pubkey,
 }
 
 impl UserInviteStats {
     pub const SEED_PREFIX: &'static [u8; 17] = b"user-invite-stats";
+
+    pub fn rolling_volume(&self, now: i64) -> u64 {
+        rolling_volume(&self.volume_buckets, self.volume_day, now as u64 / SECONDS_PER_DAY)
+    }
+
+    pub fn record_volume(&mut self, now: i64, sol_amount: u64) {
+        let day = now as u64 / SECONDS_PER_DAY;
+        record_volume(&mut self.volume_buckets, self.volume_day, day, sol_amount);
+        self.volume_day = self.volume_day.max(day);
+        self.has_traded = true;
+    }
+
+    //the owner or its delegate claims part or, without an amount, all of the claimable profit
+    pub fn claim_profit(&mut self, claimer: Pubkey, owner: Pubkey, amount: Option<u64>) -> Result<u64> {
+        require!(
+            claimer == owner || (self.claim_delegate != Pubkey::default() && claimer == self.claim_delegate),
+            CurveLaunchpadError::InvalidClaimer
+        );
+
+        let claim_amount = amount.unwrap_or(self.profit_claimable);
+        require!(claim_amount > 0, CurveLaunchpadError::NotClaimableFee);
+        require!(claim_amount <= self.profit_claimable, CurveLaunchpadError::NotClaimableFee);
+        self.profit_claimable -= claim_amount;
+        self.profit_claim_accumulated += claim_amount;
+
+        Ok(claim_amount)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_profit() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let mut user_invite_account = UserInviteStats { profit_claimable: 100, ..Default::default() };
+
+        //only the owner until a delegate is set
+        assert_eq!(
+            user_invite_account.claim_profit(delegate, owner, None).unwrap_err(),
+            CurveLaunchpadError::InvalidClaimer.into()
+        );
+        assert_eq!(user_invite_account.claim_profit(owner, owner, Some(30)).unwrap(), 30);
 
+        user_invite_account.claim_delegate = delegate;
+        assert_eq!(
+            user_invite_account.claim_profit(delegate, owner, Some(71)).unwrap_err(),
+            CurveLaunchpadError::NotClaimableFee.into()
+        );
+        assert_eq!(user_invite_account.claim_profit(delegate, owner, None).unwrap(), 70);
+        assert_eq!(user_invite_account.profit_claimable, 0);
+        assert_eq!(user_invite_account.profit_claim_accumulated, 100);
+        assert_eq!(
+            user_invite_account.claim_profit(owner, owner, None).unwrap_err(),
+            CurveLaunchpadError::NotClaimableFee.into()
+        );
+    }
+
+    #[test]
+    fn test_record_volume() {
+        let day = SECONDS_PER_DAY as i64;
+        let mut user_invite_account = UserInviteStats::default();
+
+        user_invite_account.record_volume(10 * day, 100);
+        user_invite_account.record_volume(10 * day + 5, 50);
+        user_invite_account.record_volume(20 * day, 25);
+        assert!(user_invite_account.has_traded);
+        assert_eq!(user_invite_account.rolling_volume(20 * day), 175);
+
+        //the first day leaves the window after VOLUME_WINDOW_DAYS
+        assert_eq!(user_invite_account.rolling_volume((10 + VOLUME_WINDOW_DAYS as i64) * day), 25);
+        assert_eq!(user_invite_account.rolling_volume((20 + VOLUME_WINDOW_DAYS as i64) * day), 0);
+    }
+}